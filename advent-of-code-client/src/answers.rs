@@ -0,0 +1,269 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Day, Error, Level, Result, Year};
+
+const ANSWERS_DIRECTORY: &str = ".answers";
+
+/// History of the answers submitted to Advent of Code, stored locally so
+/// solutions can be checked for regressions without contacting the server,
+/// and so known incorrect answers are not submitted again.
+///
+/// Answers are stored per year in `.answers/<year>.json` in the data
+/// directory, see [crate::default_data_dir], and are recorded
+/// automatically by [crate::AocClient::submit].
+#[derive(Debug)]
+pub struct AnswerMap {
+    directory: PathBuf,
+    year: Year,
+    map: HashMap<String, DayAnswers>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+struct DayAnswers {
+    #[serde(default, skip_serializing_if = "LevelAnswers::is_empty")]
+    a: LevelAnswers,
+    #[serde(default, skip_serializing_if = "LevelAnswers::is_empty")]
+    b: LevelAnswers,
+}
+
+impl DayAnswers {
+    fn get(&self, level: Level) -> &LevelAnswers {
+        match level {
+            Level::A => &self.a,
+            Level::B => &self.b,
+        }
+    }
+
+    fn get_mut(&mut self, level: Level) -> &mut LevelAnswers {
+        match level {
+            Level::A => &mut self.a,
+            Level::B => &mut self.b,
+        }
+    }
+}
+
+/// Everything known about the answers for a single level.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct LevelAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    correct: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    incorrect: Vec<String>,
+    /// Largest answer that was too low. The correct answer is above this.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    lower_bound: Option<i64>,
+    /// Smallest answer that was too high. The correct answer is below this.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    upper_bound: Option<i64>,
+}
+
+impl LevelAnswers {
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Hint given by Advent of Code for why an answer is incorrect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// Reason an answer is known to be incorrect without submitting it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KnownIncorrect {
+    /// The exact answer has been rejected before.
+    PreviouslySubmitted,
+    /// The answer is at or below an answer that was too low.
+    TooLow { lower_bound: i64 },
+    /// The answer is at or above an answer that was too high.
+    TooHigh { upper_bound: i64 },
+}
+
+impl AnswerMap {
+    /// Load the known answers for a year from the `.answers` directory in
+    /// `data_dir`. A year without any stored answers is loaded as empty.
+    pub fn load(data_dir: &Path, year: Year) -> Result<AnswerMap> {
+        let directory = data_dir.join(ANSWERS_DIRECTORY);
+        let filename = Self::get_filename(&directory, year);
+        let content = match fs::read_to_string(filename.as_path()) {
+            Ok(value) => value,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(AnswerMap {
+                    directory,
+                    year,
+                    map: HashMap::new(),
+                })
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        let map = serde_json::from_str(&content).map_err(|source| Error::CorruptStateFile {
+            path: filename,
+            source,
+        })?;
+        Ok(AnswerMap {
+            directory,
+            year,
+            map,
+        })
+    }
+
+    /// Get the known correct answer for a given day and level.
+    pub fn get_answer(&self, day: Day, level: Level) -> Option<&str> {
+        self.get(day, level)?.correct.as_deref()
+    }
+
+    /// Record the correct answer for a given day and level.
+    pub fn set_answer(&mut self, day: Day, level: Level, answer: &str) -> Result<()> {
+        self.get_mut(day, level).correct = Some(answer.to_string());
+        self.store()
+    }
+
+    /// Record an answer that was rejected, together with the hint about
+    /// whether it was too high or too low, if one was given.
+    pub fn add_incorrect(
+        &mut self,
+        day: Day,
+        level: Level,
+        answer: &str,
+        hint: Option<Hint>,
+    ) -> Result<()> {
+        let entry = self.get_mut(day, level);
+        if !entry.incorrect.iter().any(|x| x == answer) {
+            entry.incorrect.push(answer.to_string());
+        }
+
+        match (hint, answer.trim().parse::<i64>()) {
+            (Some(Hint::TooHigh), Ok(value)) => {
+                entry.upper_bound = Some(entry.upper_bound.map_or(value, |x| x.min(value)));
+            }
+            (Some(Hint::TooLow), Ok(value)) => {
+                entry.lower_bound = Some(entry.lower_bound.map_or(value, |x| x.max(value)));
+            }
+            _ => {}
+        }
+
+        self.store()
+    }
+
+    /// Check whether an answer is known to be incorrect, either because it
+    /// has been rejected before, or because it is outside the bounds given
+    /// by previous too high/too low hints.
+    pub fn check_incorrect(&self, day: Day, level: Level, answer: &str) -> Option<KnownIncorrect> {
+        let entry = self.get(day, level)?;
+        if entry.incorrect.iter().any(|x| x == answer) {
+            return Some(KnownIncorrect::PreviouslySubmitted);
+        }
+
+        let value = answer.trim().parse::<i64>().ok()?;
+        match (entry.lower_bound, entry.upper_bound) {
+            (Some(lower_bound), _) if value <= lower_bound => {
+                Some(KnownIncorrect::TooLow { lower_bound })
+            }
+            (_, Some(upper_bound)) if value >= upper_bound => {
+                Some(KnownIncorrect::TooHigh { upper_bound })
+            }
+            _ => None,
+        }
+    }
+
+    fn get(&self, day: Day, level: Level) -> Option<&LevelAnswers> {
+        self.map
+            .get(&Self::day_to_key(day))
+            .map(|answers| answers.get(level))
+    }
+
+    fn get_mut(&mut self, day: Day, level: Level) -> &mut LevelAnswers {
+        self.map
+            .entry(Self::day_to_key(day))
+            .or_default()
+            .get_mut(level)
+    }
+
+    fn day_to_key(day: Day) -> String {
+        format!("day{}", day)
+    }
+
+    fn get_filename(directory: &Path, year: Year) -> PathBuf {
+        directory.join(format!("{}.json", year.as_int()))
+    }
+
+    fn store(&self) -> Result<()> {
+        fs::create_dir_all(&self.directory)?;
+        let filename = Self::get_filename(&self.directory, self.year);
+        let content =
+            serde_json::to_string_pretty(&self.map).map_err(|source| Error::CorruptStateFile {
+                path: filename.clone(),
+                source,
+            })?;
+        fs::write(filename, content)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn answer_map(json: &str) -> AnswerMap {
+        AnswerMap {
+            directory: PathBuf::new(),
+            year: Year::Y2017,
+            map: serde_json::from_str(json).unwrap(),
+        }
+    }
+
+    #[test]
+    fn serialize_answers_test() {
+        let mut answers = DayAnswers::default();
+        answers.get_mut(Level::B).correct = Some("42".to_string());
+
+        assert_eq!(
+            serde_json::to_string(&answers).unwrap(),
+            r#"{"b":{"correct":"42"}}"#
+        );
+    }
+
+    #[test]
+    fn get_answer_test() {
+        let map = answer_map(r#"{"day1":{"a":{"correct":"1044"}}}"#);
+
+        assert_eq!(map.get_answer(1, Level::A), Some("1044"));
+        assert_eq!(map.get_answer(1, Level::B), None);
+        assert_eq!(map.get_answer(2, Level::A), None);
+    }
+
+    #[test]
+    fn check_incorrect_test() {
+        let map = answer_map(
+            r#"{"day1":{"a":{"incorrect":["10","abc","90"],"lower_bound":10,"upper_bound":90}}}"#,
+        );
+
+        assert_eq!(
+            map.check_incorrect(1, Level::A, "abc"),
+            Some(KnownIncorrect::PreviouslySubmitted)
+        );
+        assert_eq!(
+            map.check_incorrect(1, Level::A, "5"),
+            Some(KnownIncorrect::TooLow { lower_bound: 10 })
+        );
+        assert_eq!(
+            map.check_incorrect(1, Level::A, "90"),
+            Some(KnownIncorrect::PreviouslySubmitted)
+        );
+        assert_eq!(
+            map.check_incorrect(1, Level::A, "100"),
+            Some(KnownIncorrect::TooHigh { upper_bound: 90 })
+        );
+        assert_eq!(map.check_incorrect(1, Level::A, "50"), None);
+        assert_eq!(map.check_incorrect(1, Level::B, "50"), None);
+    }
+}
@@ -1,9 +1,15 @@
-use advent_of_code_client::{AocClient, Level, Problem, Year};
+use std::path::PathBuf;
+
+use advent_of_code_client::{
+    default_bundle_path, export_bundle, import_bundle, render_dashboard, verify_cached_inputs,
+    AocClient, AocClientBuilder, CacheKey, Error, Format, InputStatus, Level, Problem, Year,
+    YearProgress,
+};
 use anyhow::anyhow;
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use colored::Colorize;
 
-/// Arguments for the CLI.
+/// Arguments for the CLI. Without a subcommand, answers are submitted.
 #[derive(Debug, Parser)]
 #[command(author, version, about, long_about = Some(r#"Client to interact with Advent of Code. Used to submit answer for the daily puzzles.
 
@@ -12,11 +18,174 @@ To retrive your personal session token (varies by browser):
 - Open the developer settings in your browser (F12)
 - Go to `application` -> `Cookies`.
 - You should see a session variable - this is the token we need."#
-))]
-struct Args {
-    /// Year of AoC to submit puzzle solution for.
-    #[arg(value_parser = clap::value_parser!(u16).range(2015..=Year::max() as i64))]
-    year: u16,
+), args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    submit: Option<SubmitArgs>,
+
+    #[command(flatten)]
+    client: ClientArgs,
+}
+
+#[derive(Debug, Clone, clap::Args)]
+struct ClientArgs {
+    /// Token to use for authenticating against Advent of Code.
+    ///
+    /// If not provided it will default to the token of the profile, or
+    /// look for `AOC_TOKEN` in our current environment.
+    #[arg(short = 't', long, global = true)]
+    token: Option<String>,
+
+    /// Profile in the config file to use the token of. Each profile has its
+    /// own inputs, stars, and answers.
+    ///
+    /// If not provided it will default to `AOC_PROFILE`.
+    #[arg(short = 'p', long, global = true)]
+    profile: Option<String>,
+
+    /// Never send requests to Advent of Code, and only use cached inputs and
+    /// descriptions.
+    ///
+    /// Can also be enabled by setting `AOC_OFFLINE`.
+    #[arg(long, global = true)]
+    offline: bool,
+}
+
+impl ClientArgs {
+    fn builder(&self) -> AocClientBuilder {
+        let builder = AocClient::builder();
+        let builder = if self.offline {
+            builder.offline(true)
+        } else {
+            builder
+        };
+        let builder = match &self.profile {
+            Some(profile) => builder.profile(profile),
+            None => builder,
+        };
+        match &self.token {
+            Some(token) => builder.token(token),
+            None => builder,
+        }
+    }
+
+    /// Create a client from the token or profile, or from `AOC_TOKEN` if none is given.
+    fn build(&self) -> advent_of_code_client::Result<AocClient> {
+        self.builder().build()
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Show the description of a puzzle.
+    Read(ReadArgs),
+    /// Download the input for a puzzle and print it.
+    Fetch(FetchArgs),
+    /// Show the standings of a private leaderboard.
+    Leaderboard(LeaderboardArgs),
+    /// Update the locally tracked stars with the stars on Advent of Code, so
+    /// problems solved on other machines are not submitted again.
+    Sync(SyncArgs),
+    /// Finish Advent of Code for a year, which gives the second star of day 25
+    /// once all other stars of the year are collected.
+    Finish(FinishArgs),
+    /// Show the stars and cached inputs for all years.
+    Status,
+    /// Show the name of the user the session token belongs to.
+    Whoami,
+    /// Check or repair the cached inputs.
+    #[command(subcommand)]
+    Cache(CacheCommand),
+}
+
+#[derive(Debug, Subcommand)]
+enum CacheCommand {
+    /// Check that the cached inputs are not corrupted, truncated, or error pages.
+    Verify,
+    /// Download the input for a puzzle again, replacing the cached input.
+    Refetch(ProblemArgs),
+    /// Encrypt the cached inputs into a bundle, which is safe to commit.
+    Export(BundleArgs),
+    /// Decrypt the inputs in a bundle into the cache.
+    Import(BundleArgs),
+}
+
+#[derive(Debug, clap::Args)]
+struct BundleArgs {
+    /// Location of the bundle. Defaults to `.input.enc` in the data directory,
+    /// which inputs are also read from when they are not cached.
+    path: Option<PathBuf>,
+}
+
+#[derive(Debug, clap::Args)]
+struct ProblemArgs {
+    /// Year of AoC puzzle, e.g. 2023.
+    year: Year,
+    /// Day of AoC puzzle.
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+impl ProblemArgs {
+    fn problem(&self) -> Problem {
+        (self.year, self.day).into()
+    }
+}
+
+impl SubmitArgs {
+    fn problem(&self) -> Problem {
+        (self.year, self.day).into()
+    }
+}
+
+#[derive(Debug, clap::Args)]
+struct ReadArgs {
+    #[command(flatten)]
+    problem: ProblemArgs,
+
+    /// Print the description as Markdown instead of formatted for the terminal.
+    #[arg(short = 'm', long)]
+    markdown: bool,
+}
+
+#[derive(Debug, clap::Args)]
+struct FetchArgs {
+    #[command(flatten)]
+    problem: ProblemArgs,
+
+    /// If the puzzle is not unlocked yet, wait for it and then fetch the input.
+    #[arg(short = 'w', long)]
+    wait: bool,
+}
+
+#[derive(Debug, clap::Args)]
+struct LeaderboardArgs {
+    /// Year of AoC to show the leaderboard for, e.g. 2023.
+    year: Year,
+    /// Id of the leaderboard, which is the number at the end of its URL.
+    id: u64,
+}
+
+#[derive(Debug, clap::Args)]
+struct SyncArgs {
+    /// Years of AoC to sync the stars for, e.g. 2023.
+    #[arg(required = true)]
+    years: Vec<Year>,
+}
+
+#[derive(Debug, clap::Args)]
+struct FinishArgs {
+    /// Year of AoC to finish, e.g. 2023.
+    year: Year,
+}
+
+#[derive(Debug, clap::Args)]
+struct SubmitArgs {
+    /// Year of AoC to submit puzzle solution for, e.g. 2023.
+    year: Year,
     /// Day of AoC to submit puzzle solution for.
     #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
     day: u8,
@@ -29,26 +198,198 @@ struct Args {
     #[arg(short = 'b', long)]
     answer_b: Option<String>,
 
-    /// Token to use for authenticating against Advent of Code.
-    ///
-    /// If not provided it will default to look for `AOC_TOKEN`
-    /// in our current environment.
-    ///
-    /// Program panics if neither --token or `AOC_TOKEN` is provided.
-    #[arg(short = 't', long)]
-    token: Option<String>,
+    /// When an answer is submitted too soon after the previous one, wait and submit it again.
+    #[arg(short = 'w', long)]
+    wait: bool,
 }
 
-impl Args {
-    fn problem(&self) -> Result<Problem, String> {
-        (self.year, self.day).try_into()
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+
+    match (cli.command, cli.submit) {
+        (Some(Command::Read(args)), _) => read(&cli.client, args),
+        (Some(Command::Fetch(args)), _) => fetch(&cli.client, args),
+        (Some(Command::Leaderboard(args)), _) => leaderboard(&cli.client, args),
+        (Some(Command::Sync(args)), _) => sync(&cli.client, args),
+        (Some(Command::Finish(args)), _) => finish(&cli.client, args),
+        (Some(Command::Whoami), _) => whoami(&cli.client),
+        (Some(Command::Cache(command)), _) => cache(&cli.client, command),
+        (Some(Command::Status), _) => {
+            print!(
+                "{}",
+                render_dashboard(
+                    &YearProgress::load_all(&cli.client.builder().resolved_data_dir()?)?,
+                    None
+                )
+            );
+            Ok(())
+        }
+        (None, args) => submit(
+            &cli.client,
+            args.expect("Arguments are required without a subcommand"),
+        ),
     }
 }
 
-fn main() -> anyhow::Result<()> {
-    let args = Args::parse();
+/// Print the description of a puzzle.
+fn read(client: &ClientArgs, args: ReadArgs) -> anyhow::Result<()> {
+    let description = client.build()?.get_description(args.problem.problem())?;
+    let format = if args.markdown {
+        Format::Markdown
+    } else {
+        Format::Terminal
+    };
+    println!("{}", description.render(format));
+
+    Ok(())
+}
+
+/// Print the name of the user, which fails if the token has expired.
+fn whoami(client: &ClientArgs) -> anyhow::Result<()> {
+    let name = client.build()?.whoami()?;
+    println!("Logged in as {}", name.green());
+
+    Ok(())
+}
+
+/// Verify or refetch the cached inputs.
+fn cache(client: &ClientArgs, command: CacheCommand) -> anyhow::Result<()> {
+    match command {
+        CacheCommand::Verify => {
+            let inputs = verify_cached_inputs(&client.builder().resolved_data_dir()?)?;
+            let mut invalid = 0;
+            for (problem, status) in &inputs {
+                match status {
+                    InputStatus::Valid => {}
+                    InputStatus::Unverified => {
+                        println!("{problem}: {}", status.to_string().yellow())
+                    }
+                    _ => {
+                        invalid += 1;
+                        println!("{problem}: {}", status.to_string().red());
+                    }
+                }
+            }
+
+            if invalid > 0 {
+                return Err(anyhow!(
+                    "{invalid} of {} cached input(s) are invalid. Use `aoc cache refetch <year> <day>` to download them again",
+                    inputs.len()
+                ));
+            }
+            println!(
+                "{}",
+                format!("{} cached input(s) checked", inputs.len()).green()
+            );
+        }
+        CacheCommand::Refetch(args) => {
+            let problem = args.problem();
+            client.build()?.refetch_input(problem)?;
+            println!("{}", format!("Input for {problem} downloaded").green());
+        }
+        CacheCommand::Export(args) => {
+            let data_dir = client.builder().resolved_data_dir()?;
+            let path = args.path.unwrap_or_else(|| default_bundle_path(&data_dir));
+            let count = export_bundle(&data_dir, &cache_key()?, &path)?;
+            println!(
+                "{}",
+                format!("{count} input(s) encrypted into {}", path.display()).green()
+            );
+        }
+        CacheCommand::Import(args) => {
+            let data_dir = client.builder().resolved_data_dir()?;
+            let path = args.path.unwrap_or_else(|| default_bundle_path(&data_dir));
+            let count = import_bundle(&data_dir, &cache_key()?, &path)?;
+            println!(
+                "{}",
+                format!("{count} input(s) imported from {}", path.display()).green()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Key for encrypted bundles, which is required to export or import them.
+fn cache_key() -> anyhow::Result<CacheKey> {
+    Ok(CacheKey::from_env()?.ok_or(Error::MissingCacheKey)?)
+}
+
+/// Print the input for a puzzle, downloading it if it is not cached.
+fn fetch(client: &ClientArgs, args: FetchArgs) -> anyhow::Result<()> {
+    let input = client
+        .build()?
+        .wait_for_unlock(args.wait)
+        .get_input(args.problem.problem())?;
+    print!("{input}");
+
+    Ok(())
+}
+
+const NAME_WIDTH: usize = 24;
+
+/// Print a private leaderboard as a table, with a column for the stars of each day.
+fn leaderboard(client: &ClientArgs, args: LeaderboardArgs) -> anyhow::Result<()> {
+    let year = args.year;
+    let leaderboard = client.build()?.private_leaderboard(year, args.id)?;
+
+    println!("Private leaderboard {} for {year}", args.id);
+    let days: String = (1..=25).map(|day| (day % 10).to_string()).collect();
+    println!(
+        "{}",
+        format!(
+            "{:>4} | {:<NAME_WIDTH$} | {:>5} | {:>5} | {days}",
+            "#", "Name", "Score", "Stars"
+        )
+        .cyan()
+    );
+
+    for (rank, member) in leaderboard.members_by_score().into_iter().enumerate() {
+        let stars: String = (1..=25)
+            .map(|day| {
+                match (member.star(day, Level::A), member.star(day, Level::B)) {
+                    (Some(_), Some(_)) => "*".yellow(),
+                    (Some(_), None) => "*".blue(),
+                    _ => ".".dimmed(),
+                }
+                .to_string()
+            })
+            .collect();
+        let name: String = member.display_name().chars().take(NAME_WIDTH).collect();
+
+        println!(
+            "{:>4} | {name:<NAME_WIDTH$} | {:>5} | {:>5} | {stars}",
+            format!("{})", rank + 1),
+            member.local_score.to_string().green(),
+            member.stars,
+        );
+    }
+
+    Ok(())
+}
+
+/// Sync the stars for each of the years.
+fn sync(client: &ClientArgs, args: SyncArgs) -> anyhow::Result<()> {
+    let client = client.build()?;
+    for year in args.years {
+        let updated = client.sync_stars(year)?;
+        println!("{year}: {}", format!("{updated} day(s) updated").green());
+    }
+
+    Ok(())
+}
+
+/// Finish the year, which is the only way to get the second star of day 25.
+fn finish(client: &ClientArgs, args: FinishArgs) -> anyhow::Result<()> {
+    let result = client.build()?.finish(args.year)?;
+    println!("{result}");
+
+    Ok(())
+}
 
-    let problem = args.problem().expect("Clap parser handles verification");
+/// Submit the answers for a puzzle.
+fn submit(client: &ClientArgs, args: SubmitArgs) -> anyhow::Result<()> {
+    let problem = args.problem();
     if args.answer_a.is_none() && args.answer_b.is_none() {
         return Err(anyhow!(
             "No answer provided for either part A or part B. Please provide at least one answer"
@@ -56,11 +397,19 @@ fn main() -> anyhow::Result<()> {
         ));
     }
 
-    let client = args.token.map(AocClient::from_token).unwrap_or_default();
+    let client = client.build()?.wait_when_too_recent(args.wait);
 
     if let Some(answer) = args.answer_a {
         let result = client.submit(problem, Level::A, &answer)?;
         println!("{result}");
+        if let Some(wait) = result
+            .wait_time()
+            .filter(|_| args.answer_b.is_some() && !args.wait)
+        {
+            return Err(anyhow!(
+                "Not submitting part B, as no answer can be submitted for {wait:?}"
+            ));
+        }
     }
 
     if let Some(answer) = args.answer_b {
@@ -0,0 +1,274 @@
+use std::{env, path::PathBuf, time::Duration};
+
+use reqwest::{
+    blocking::Client,
+    header::{HeaderMap, HeaderValue, COOKIE},
+    Url,
+};
+
+use crate::{
+    default_data_dir, default_url_for_advent_of_code, get_token,
+    input::is_offline_from_env,
+    profile::{profile_data_dir, Config, PROFILE_NAME},
+    AocClient, Error, Result,
+};
+
+/// Contact info used in the user agent if none is given.
+const DEFAULT_CONTACT: &str = "oliverfl@live.dk";
+
+/// Builder for an [AocClient], to configure how it connects to Advent of Code.
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use advent_of_code_client::{AocClientBuilder, RetryPolicy};
+/// let client = AocClientBuilder::new()
+///     .token("your personal session token")
+///     .user_agent_contact("you@example.com")
+///     .timeout(Duration::from_secs(10))
+///     .retry_policy(RetryPolicy::default().max_retries(5))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct AocClientBuilder {
+    base_url: Url,
+    token: Option<String>,
+    profile: Option<String>,
+    contact: String,
+    timeout: Duration,
+    retry_policy: RetryPolicy,
+    data_dir: Option<PathBuf>,
+    offline: Option<bool>,
+}
+
+impl Default for AocClientBuilder {
+    fn default() -> Self {
+        Self {
+            base_url: default_url_for_advent_of_code(),
+            token: None,
+            profile: None,
+            contact: DEFAULT_CONTACT.to_string(),
+            timeout: Duration::from_secs(30),
+            retry_policy: RetryPolicy::default(),
+            data_dir: None,
+            offline: None,
+        }
+    }
+}
+
+impl AocClientBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// URL of the Advent of Code server, e.g. a local mirror or a mock server.
+    pub fn base_url(mut self, base_url: Url) -> Self {
+        self.base_url = base_url;
+        self
+    }
+
+    /// Session token to authenticate with. If not set, it is read from `AOC_TOKEN`.
+    pub fn token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Profile in the config file to use, see [crate::Config]. If not set, it
+    /// is read from `AOC_PROFILE`. The token of the profile is used unless a
+    /// token is set, and the data of the profile is kept in its own directory.
+    pub fn profile(mut self, profile: impl Into<String>) -> Self {
+        self.profile = Some(profile.into());
+        self
+    }
+
+    /// Contact info, e.g. an email, to include in the user agent, so the
+    /// Advent of Code team can get in touch in case of problems.
+    pub fn user_agent_contact(mut self, contact: impl Into<String>) -> Self {
+        self.contact = contact.into();
+        self
+    }
+
+    /// Timeout for each request. Defaults to 30 seconds.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// How to retry requests that fail because of network errors or server
+    /// errors. Answers are never retried, as they might have been received.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Directory to store data in. Defaults to [default_data_dir].
+    pub fn data_dir(mut self, data_dir: impl Into<PathBuf>) -> Self {
+        self.data_dir = Some(data_dir.into());
+        self
+    }
+
+    /// Never send requests to Advent of Code, and only use cached inputs and
+    /// descriptions. No token is needed. If not set, it is enabled by setting
+    /// `AOC_OFFLINE`.
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = Some(offline);
+        self
+    }
+
+    /// Directory the client will store data in. For a profile, this is
+    /// `.profiles/<name>` in the data directory.
+    pub fn resolved_data_dir(&self) -> Result<PathBuf> {
+        let data_dir = self.data_dir.clone().unwrap_or_else(default_data_dir);
+        match self.resolved_profile() {
+            Some(profile) => profile_data_dir(&data_dir, &profile),
+            None => Ok(data_dir),
+        }
+    }
+
+    fn resolved_profile(&self) -> Option<String> {
+        self.profile
+            .clone()
+            .or_else(|| env::var(PROFILE_NAME).ok().filter(|p| !p.is_empty()))
+    }
+
+    /// Build the client. Fails with [Error::MissingToken] if no token or
+    /// profile is set and `AOC_TOKEN` is missing, unless in offline mode.
+    /// In offline mode the token of a profile is not loaded.
+    pub fn build(self) -> Result<AocClient> {
+        let data_dir = self.resolved_data_dir()?;
+        let profile = self.resolved_profile();
+        let offline = self.offline.unwrap_or_else(is_offline_from_env);
+        let token = match (self.token, profile) {
+            (Some(token), _) => Some(token),
+            (None, Some(_)) if offline => None,
+            (None, Some(profile)) => Some(Config::load()?.token(&profile)?.to_string()),
+            (None, None) => match get_token() {
+                Ok(token) => Some(token),
+                Err(_) if offline => None,
+                Err(e) => return Err(e),
+            },
+        };
+
+        let mut headers = HeaderMap::new();
+        if let Some(token) = token {
+            headers.insert(
+                COOKIE,
+                HeaderValue::from_str(&format!("session={token}"))
+                    .map_err(|_| Error::InvalidToken)?,
+            );
+        }
+        let http_client = Client::builder()
+            .default_headers(headers)
+            .user_agent(format!(
+                "github.com/OliverFlecke/advent-of-code-rust by {}",
+                self.contact
+            ))
+            .timeout(self.timeout)
+            .build()?;
+
+        Ok(AocClient {
+            base_url: self.base_url,
+            http_client,
+            retry_policy: self.retry_policy,
+            data_dir,
+            wait_when_too_recent: false,
+            wait_for_unlock: false,
+            offline,
+        })
+    }
+}
+
+/// Policy for retrying requests that fail because of network errors or
+/// server errors, with an exponential backoff between each attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    /// Retry twice, waiting one second before the first retry.
+    fn default() -> Self {
+        Self {
+            max_retries: 2,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Never retry failed requests.
+    pub fn none() -> Self {
+        Self::default().max_retries(0)
+    }
+
+    /// Number of times to retry a failed request.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Time to wait before the first retry, which is doubled for each retry.
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Longest time to wait between two attempts.
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Time to wait before a retry, or `None` if no more retries should be done.
+    /// `retry` is zero-indexed.
+    pub(crate) fn backoff(&self, retry: u32) -> Option<Duration> {
+        (retry < self.max_retries).then(|| {
+            self.initial_backoff
+                .saturating_mul(2_u32.saturating_pow(retry))
+                .min(self.max_backoff)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn backoff_is_exponential() {
+        let policy = RetryPolicy::default()
+            .max_retries(4)
+            .initial_backoff(Duration::from_secs(1))
+            .max_backoff(Duration::from_secs(5));
+
+        assert_eq!(policy.backoff(0), Some(Duration::from_secs(1)));
+        assert_eq!(policy.backoff(1), Some(Duration::from_secs(2)));
+        assert_eq!(policy.backoff(2), Some(Duration::from_secs(4)));
+        assert_eq!(policy.backoff(3), Some(Duration::from_secs(5)));
+        assert_eq!(policy.backoff(4), None);
+        assert_eq!(RetryPolicy::none().backoff(0), None);
+    }
+
+    #[test]
+    fn offline_does_not_load_profile_token() {
+        let client = AocClientBuilder::new()
+            .profile("not-in-config")
+            .offline(true)
+            .build();
+
+        assert!(client.is_ok());
+    }
+
+    #[test]
+    fn reject_invalid_profile_name() {
+        let client = AocClientBuilder::new()
+            .profile("../other")
+            .offline(true)
+            .build();
+
+        assert!(matches!(client, Err(Error::InvalidProfileName(_))));
+    }
+}
@@ -1,23 +1,426 @@
 //! Functions to cache input locally in files.
+//!
+//! Each input is stored with a sidecar file with its checksum and the time it
+//! was fetched, so corrupted or truncated inputs can be detected.
+//!
+//! Inputs can also be kept in an encrypted bundle, which is safe to commit to
+//! a shared repository, as Advent of Code asks for inputs not to be published.
+//! Everyone with the key can read inputs from the bundle.
 use std::{
-    fs,
+    collections::BTreeMap,
+    env,
+    fmt::Display,
+    fs, io,
     path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
 };
 
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    ChaCha20Poly1305, Key, Nonce,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
 use super::Problem;
-use crate::Year;
+use crate::{profile::config_path, Day, Error, Result, Year};
+
+/// Environment variable with the key for the encrypted bundle.
+pub(crate) const CACHE_KEY_NAME: &str = "AOC_CACHE_KEY";
+
+/// Environment variable to set the location of the file with the key.
+const CACHE_KEY_FILE_NAME: &str = "AOC_CACHE_KEY_FILE";
+
+/// Start of an encrypted bundle, to recognize it and its format.
+const BUNDLE_HEADER: &[u8] = b"AOC-INPUTS-1\n";
+
+/// Inputs in a bundle by year and day.
+type Bundle = BTreeMap<u16, BTreeMap<Day, String>>;
+
+/// Metadata stored next to a cached input.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct InputMetadata {
+    /// SHA-256 of the input as hex.
+    sha256: String,
+    /// Time the input was fetched in seconds since the Unix epoch.
+    fetched_at: u64,
+}
+
+/// Status of a cached input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputStatus {
+    /// The input matches its checksum.
+    Valid,
+    /// The input looks valid, but has no checksum to verify it against, e.g.
+    /// as it was cached before checksums were stored.
+    Unverified,
+    /// The input is not a puzzle input, e.g. an error page.
+    Invalid(String),
+    /// The input does not match its checksum, e.g. as it is truncated.
+    ChecksumMismatch,
+}
+
+impl InputStatus {
+    /// Whether the input can be used.
+    pub fn is_usable(&self) -> bool {
+        matches!(self, InputStatus::Valid | InputStatus::Unverified)
+    }
+}
+
+impl Display for InputStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputStatus::Valid => write!(f, "valid"),
+            InputStatus::Unverified => write!(f, "no checksum stored"),
+            InputStatus::Invalid(reason) => write!(f, "{reason}"),
+            InputStatus::ChecksumMismatch => write!(
+                f,
+                "does not match its checksum, it might be corrupted or truncated"
+            ),
+        }
+    }
+}
+
+/// Store an input in the cache, together with its checksum and the current time.
+pub fn store_input_in_cache(data_dir: &Path, problem: Problem, input: &str) -> io::Result<()> {
+    fs::create_dir_all(get_input_cache_directory(data_dir, problem.year()))?;
+    fs::write(get_input_cache_full_filename(data_dir, problem), input)?;
+
+    let metadata = InputMetadata {
+        sha256: checksum(input),
+        fetched_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| time.as_secs()),
+    };
+    fs::write(
+        get_input_metadata_full_filename(data_dir, problem),
+        serde_json::to_string(&metadata)?,
+    )
+}
+
+/// Read a cached input, if it exists.
+pub fn read_input_from_cache(data_dir: &Path, problem: Problem) -> io::Result<Option<String>> {
+    match fs::read_to_string(get_input_cache_full_filename(data_dir, problem)) {
+        Ok(input) => Ok(Some(input)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Check a cached input against its checksum, and that it is a puzzle input.
+pub fn check_cached_input(data_dir: &Path, problem: Problem, input: &str) -> InputStatus {
+    if let Err(reason) = validate_input(input) {
+        return InputStatus::Invalid(reason);
+    }
+
+    let metadata = fs::read_to_string(get_input_metadata_full_filename(data_dir, problem))
+        .ok()
+        .and_then(|content| serde_json::from_str::<InputMetadata>(&content).ok());
+    match metadata {
+        Some(metadata) if metadata.sha256 == checksum(input) => InputStatus::Valid,
+        Some(_) => InputStatus::ChecksumMismatch,
+        None => InputStatus::Unverified,
+    }
+}
+
+/// Check all cached inputs in the data directory.
+pub fn verify_cached_inputs(data_dir: &Path) -> io::Result<Vec<(Problem, InputStatus)>> {
+    Ok(cached_inputs(data_dir)?
+        .into_iter()
+        .map(|(problem, input)| (problem, check_cached_input(data_dir, problem, &input)))
+        .collect())
+}
+
+/// All inputs in the cache.
+fn cached_inputs(data_dir: &Path) -> io::Result<Vec<(Problem, String)>> {
+    let mut result = Vec::new();
+    for year in Year::all() {
+        for day in 1..=25 {
+            let problem = Problem::new(year, day);
+            if let Some(input) = read_input_from_cache(data_dir, problem)? {
+                result.push((problem, input));
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Key to encrypt and decrypt bundles with inputs.
+#[derive(Clone)]
+pub struct CacheKey(Key);
+
+impl CacheKey {
+    /// Derive a key from a secret shared by everyone using the bundle, e.g.
+    /// generated with `openssl rand -hex 32`. Surrounding whitespace is ignored.
+    pub fn new(secret: &str) -> Self {
+        Self(Key::clone_from_slice(&Sha256::digest(
+            secret.trim().as_bytes(),
+        )))
+    }
+
+    /// Find the key, which is the first of:
+    ///
+    /// - The `AOC_CACHE_KEY` environment variable.
+    /// - The file at [cache_key_path].
+    ///
+    /// Returns `None` if no key is set.
+    pub fn from_env() -> Result<Option<Self>> {
+        if let Some(secret) = env::var(CACHE_KEY_NAME).ok().filter(|key| !key.is_empty()) {
+            return Ok(Some(Self::new(&secret)));
+        }
+
+        match fs::read_to_string(cache_key_path()) {
+            Ok(secret) => Ok(Some(Self::new(&secret))),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+impl std::fmt::Debug for CacheKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CacheKey(..)")
+    }
+}
+
+/// Location of the file with the key for bundles, which is the file set in
+/// `AOC_CACHE_KEY_FILE`, or `cache.key` next to the config file.
+pub fn cache_key_path() -> PathBuf {
+    env::var_os(CACHE_KEY_FILE_NAME)
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| config_path().with_file_name("cache.key"))
+}
+
+/// Bundle in the data directory, which inputs are read from if they are not
+/// cached and cannot be downloaded. As each profile has its own data
+/// directory, each account has its own bundle.
+pub fn default_bundle_path(data_dir: &Path) -> PathBuf {
+    data_dir.join(".input.enc")
+}
+
+/// Read an input from the bundle in the data directory. Returns `None` if
+/// there is no bundle, no key, or the bundle does not have the input.
+pub fn read_input_from_bundle(data_dir: &Path, problem: Problem) -> Result<Option<String>> {
+    let path = default_bundle_path(data_dir);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let Some(key) = CacheKey::from_env()? else {
+        return Ok(None);
+    };
+
+    Ok(read_bundle(&path, &key)?
+        .get_mut(&problem.year().as_int())
+        .and_then(|inputs| inputs.remove(problem.day())))
+}
+
+/// Encrypt all valid cached inputs into the bundle at `path`. Inputs already
+/// in the bundle are kept, unless they are cached too, so the bundle must
+/// belong to the same account as the data directory. Returns the number of
+/// inputs in the bundle.
+pub fn export_bundle(data_dir: &Path, key: &CacheKey, path: &Path) -> Result<usize> {
+    let mut bundle = if path.exists() {
+        read_bundle(path, key)?
+    } else {
+        Bundle::new()
+    };
+    for (problem, input) in cached_inputs(data_dir)? {
+        if check_cached_input(data_dir, problem, &input).is_usable() {
+            bundle
+                .entry(problem.year().as_int())
+                .or_default()
+                .insert(*problem.day(), input);
+        }
+    }
+
+    let plaintext = serde_json::to_vec(&bundle).map_err(io::Error::from)?;
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = ChaCha20Poly1305::new(&key.0)
+        .encrypt(&nonce, plaintext.as_slice())
+        .map_err(|_| Error::InvalidBundle(path.to_path_buf()))?;
+    fs::write(
+        path,
+        [BUNDLE_HEADER, nonce.as_slice(), &ciphertext].concat(),
+    )?;
+
+    Ok(bundle.values().map(BTreeMap::len).sum())
+}
+
+/// Decrypt the inputs in the bundle at `path` into the cache. Inputs that are
+/// already cached and valid are not replaced. Returns the number of inputs
+/// added to the cache.
+pub fn import_bundle(data_dir: &Path, key: &CacheKey, path: &Path) -> Result<usize> {
+    let mut imported = 0;
+    for (year, inputs) in read_bundle(path, key)? {
+        let Some(year) = Year::new(year) else {
+            continue;
+        };
+        for (day, input) in inputs {
+            let problem = Problem::new(year, day);
+            let cached = read_input_from_cache(data_dir, problem)?;
+            if cached
+                .is_some_and(|cached| check_cached_input(data_dir, problem, &cached).is_usable())
+            {
+                continue;
+            }
+            validate_input(&input).map_err(|reason| Error::InvalidInput { problem, reason })?;
+            store_input_in_cache(data_dir, problem, &input)?;
+            imported += 1;
+        }
+    }
+
+    Ok(imported)
+}
+
+fn read_bundle(path: &Path, key: &CacheKey) -> Result<Bundle> {
+    let content = fs::read(path)?;
+    let invalid = || Error::InvalidBundle(path.to_path_buf());
+    let content = content.strip_prefix(BUNDLE_HEADER).ok_or_else(invalid)?;
+    if content.len() < 12 {
+        return Err(invalid());
+    }
+    let (nonce, ciphertext) = content.split_at(12);
+    let plaintext = ChaCha20Poly1305::new(&key.0)
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| invalid())?;
+
+    serde_json::from_slice(&plaintext).map_err(|_| invalid())
+}
+
+/// Normalize line endings of an input to `\n`.
+pub fn normalize_input(input: String) -> String {
+    if input.contains('\r') {
+        input.replace("\r\n", "\n")
+    } else {
+        input
+    }
+}
 
-pub fn store_input_in_cache(problem: Problem, input: &String) -> std::io::Result<()> {
-    fs::create_dir_all(get_input_cache_directory(problem.year()))?;
-    fs::write(get_input_cache_full_filename(problem), input)
+/// Check that an input is a puzzle input, and not an error page or message
+/// from Advent of Code. Returns the reason if it is not.
+pub fn validate_input(input: &str) -> Result<(), String> {
+    let start = input.trim_start().to_lowercase();
+    if input.trim().is_empty() {
+        Err("input is empty".to_string())
+    } else if start.starts_with("<!doctype") || start.starts_with("<html") {
+        Err("input is an HTML page".to_string())
+    } else if input.contains("Puzzle inputs differ by user") {
+        Err("input is a message asking to log in".to_string())
+    } else if input.contains("before it unlocks") {
+        Err("input is a message that the puzzle is not unlocked".to_string())
+    } else {
+        Ok(())
+    }
 }
 
-pub fn get_input_cache_full_filename(problem: Problem) -> PathBuf {
-    Path::new(&get_input_cache_directory(problem.year()))
+fn checksum(input: &str) -> String {
+    format!("{:x}", Sha256::digest(input.as_bytes()))
+}
+
+pub fn get_input_cache_full_filename(data_dir: &Path, problem: Problem) -> PathBuf {
+    get_input_cache_directory(data_dir, problem.year())
         .join(format!("{day}.txt", day = problem.day()))
 }
 
-/// Directory where input is cached at.
-fn get_input_cache_directory(year: &Year) -> String {
-    format!(".input/{year}/", year = year.as_int())
+/// Metadata for an input is stored next to it.
+fn get_input_metadata_full_filename(data_dir: &Path, problem: Problem) -> PathBuf {
+    get_input_cache_directory(data_dir, problem.year())
+        .join(format!("{day}.json", day = problem.day()))
+}
+
+pub fn store_description_in_cache(
+    data_dir: &Path,
+    problem: Problem,
+    description: &str,
+) -> std::io::Result<()> {
+    fs::create_dir_all(get_input_cache_directory(data_dir, problem.year()))?;
+    fs::write(
+        get_description_cache_full_filename(data_dir, problem),
+        description,
+    )
+}
+
+/// Descriptions are cached next to the input, as the HTML of the articles.
+pub fn get_description_cache_full_filename(data_dir: &Path, problem: Problem) -> PathBuf {
+    get_input_cache_directory(data_dir, problem.year())
+        .join(format!("{day}.html", day = problem.day()))
+}
+
+/// Directory in the data directory where input is cached at.
+fn get_input_cache_directory(data_dir: &Path, year: &Year) -> PathBuf {
+    data_dir.join(".input").join(year.as_int().to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utils::temp_data_dir;
+
+    #[test]
+    fn validate_inputs() {
+        assert_eq!(validate_input("1abc2\npqr3stu8vwx\n"), Ok(()));
+        assert!(validate_input("\n").is_err());
+        assert!(validate_input("<!DOCTYPE html>\n<html lang=\"en-us\">").is_err());
+        assert!(validate_input(
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+        )
+        .is_err());
+        assert!(validate_input(
+            "Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.\n"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn normalize_line_endings() {
+        assert_eq!(normalize_input("1\r\n2\r\n".to_string()), "1\n2\n");
+        assert_eq!(normalize_input("1\n2\n".to_string()), "1\n2\n");
+    }
+
+    #[test]
+    fn export_and_import_bundle() {
+        let dir = temp_data_dir();
+        let dir = dir.path();
+        let (from, to, path) = (dir.join("from"), dir.join("to"), dir.join("inputs.enc"));
+        let problem = Problem::new(Year::Y2023, 1);
+        let input = "1abc2\npqr3stu8vwx\n";
+        let key = CacheKey::new("secret");
+        store_input_in_cache(&from, problem, input).unwrap();
+
+        assert_eq!(export_bundle(&from, &key, &path).unwrap(), 1);
+        assert!(!fs::read(&path).unwrap().windows(5).any(|w| w == b"1abc2"));
+        assert!(matches!(
+            import_bundle(&to, &CacheKey::new("wrong"), &path),
+            Err(Error::InvalidBundle(_))
+        ));
+        assert_eq!(import_bundle(&to, &key, &path).unwrap(), 1);
+        assert_eq!(read_input_from_cache(&to, problem).unwrap().unwrap(), input);
+        assert_eq!(import_bundle(&to, &key, &path).unwrap(), 0);
+    }
+
+    #[test]
+    fn detect_corrupted_input() {
+        let data_dir = temp_data_dir();
+        let data_dir = data_dir.path();
+        let problem = Problem::new(Year::Y2023, 1);
+        let input = "1abc2\npqr3stu8vwx\n";
+
+        store_input_in_cache(data_dir, problem, input).unwrap();
+        assert_eq!(
+            check_cached_input(data_dir, problem, input),
+            InputStatus::Valid
+        );
+        assert_eq!(
+            check_cached_input(data_dir, problem, "1abc2\n"),
+            InputStatus::ChecksumMismatch
+        );
+
+        fs::remove_file(get_input_metadata_full_filename(data_dir, problem)).unwrap();
+        assert_eq!(
+            verify_cached_inputs(data_dir).unwrap(),
+            vec![(problem, InputStatus::Unverified)]
+        );
+    }
 }
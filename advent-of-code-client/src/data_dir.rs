@@ -0,0 +1,68 @@
+//! Location of the data stored by the client, i.e. cached inputs and
+//! descriptions, stars, answers, and the time of the next submission.
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+/// Environment variable to set the data directory.
+const DATA_DIR_NAME: &str = "AOC_DATA_DIR";
+
+/// Directories that mark a directory as the data directory.
+const DATA_DIRECTORIES: [&str; 3] = [".input", ".stars", ".answers"];
+
+/// Get the directory where data is stored, which is the first of:
+///
+/// - The directory set in the `AOC_DATA_DIR` environment variable.
+/// - The closest ancestor of the current directory, including itself, that
+///   already has data in it, i.e. an `.input`, `.stars`, or `.answers` directory.
+/// - `advent-of-code` in the XDG data directory, i.e. `$XDG_DATA_HOME` or
+///   `~/.local/share`.
+/// - The current directory.
+pub fn default_data_dir() -> PathBuf {
+    if let Some(dir) = env::var_os(DATA_DIR_NAME).filter(|dir| !dir.is_empty()) {
+        return PathBuf::from(dir);
+    }
+
+    let current_dir = env::current_dir().unwrap_or_default();
+    find_data_root(&current_dir)
+        .or_else(xdg_data_dir)
+        .unwrap_or(current_dir)
+}
+
+/// Find the closest ancestor of `start`, including itself, with data in it.
+fn find_data_root(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .find(|dir| DATA_DIRECTORIES.iter().any(|name| dir.join(name).is_dir()))
+        .map(Path::to_path_buf)
+}
+
+fn xdg_data_dir() -> Option<PathBuf> {
+    let data_home = env::var_os("XDG_DATA_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".local/share")))?;
+
+    Some(data_home.join("advent-of-code"))
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use super::*;
+    use crate::test_utils::temp_data_dir;
+
+    #[test]
+    fn find_data_root_in_ancestor() {
+        let root = temp_data_dir();
+        let root = root.path().to_path_buf();
+        let nested = root.join("a").join("b");
+        fs::create_dir_all(&nested).unwrap();
+        fs::create_dir_all(root.join(".stars")).unwrap();
+
+        assert_eq!(find_data_root(&nested), Some(root.clone()));
+        assert_eq!(find_data_root(&root), Some(root.clone()));
+    }
+}
@@ -0,0 +1,334 @@
+use std::fmt::Display;
+
+use colored::Colorize;
+use regex::Regex;
+
+/// Description of a puzzle, as shown on the puzzle page.
+///
+/// The description has one part until the first part of the puzzle is solved,
+/// after which the description of the second part is included as well.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Description {
+    parts: Vec<String>,
+}
+
+/// Example input and its answers found in a [Description]. Answers that
+/// could not be found are `None`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExampleCandidate {
+    pub input: String,
+    pub answer_a: Option<String>,
+    pub answer_b: Option<String>,
+}
+
+/// Format to render a [Description] in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Markdown,
+    /// Plain text with colors for the terminal.
+    Terminal,
+}
+
+impl Description {
+    /// Extract the description from the HTML of a puzzle page, or from the
+    /// cached articles. Returns `None` if the page has no description.
+    pub fn from_html(html: &str) -> Option<Self> {
+        let re = Regex::new(r#"(?s)<article class="day-desc">.*?</article>"#)
+            .expect("Invalid regex for puzzle description");
+        let parts: Vec<String> = re.find_iter(html).map(|m| m.as_str().to_string()).collect();
+
+        (!parts.is_empty()).then_some(Self { parts })
+    }
+
+    /// The HTML of the articles with the description, which is what is cached.
+    pub fn to_html(&self) -> String {
+        self.parts.join("\n")
+    }
+
+    /// Whether the description of the second part is included.
+    pub fn has_part_two(&self) -> bool {
+        self.parts.len() > 1
+    }
+
+    /// Find the examples in the description. This is a heuristic, where the
+    /// input is the first code block with more than one line, and the answer is
+    /// the last emphasized code in each part. If the second part does not have
+    /// its own example, its answer is for the example from the first part.
+    pub fn examples(&self) -> Vec<ExampleCandidate> {
+        let mut examples: Vec<ExampleCandidate> = Vec::new();
+
+        for (index, part) in self.parts.iter().enumerate() {
+            let answer = last_answer(part);
+            let (answer_a, answer_b) = if index == 0 {
+                (answer, None)
+            } else {
+                (None, answer)
+            };
+
+            match (example_input(part), examples.last_mut()) {
+                (Some(input), _) => examples.push(ExampleCandidate {
+                    input,
+                    answer_a,
+                    answer_b,
+                }),
+                (None, Some(example)) if index > 0 => example.answer_b = answer_b,
+                _ => {}
+            }
+        }
+
+        examples
+    }
+
+    /// Render the description in the given format.
+    pub fn render(&self, format: Format) -> String {
+        self.parts
+            .iter()
+            .map(|part| render_html(part, format))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Display for Description {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render(Format::Terminal))
+    }
+}
+
+/// Convert the HTML of an article to the given format. Only the small set of
+/// tags used in the puzzle descriptions are handled, all others are ignored.
+fn render_html(html: &str, format: Format) -> String {
+    let token = Regex::new(r#"<(?<close>/?)(?<tag>\w+)(?<attributes>[^>]*)>|(?<text>[^<]+)"#)
+        .expect("Invalid regex for HTML tokens");
+    let href = Regex::new(r#"href="(?<href>[^"]*)""#).expect("Invalid regex for links");
+
+    let mut output = String::new();
+    let mut in_pre = false;
+    let mut in_heading = false;
+    let mut emphasis = 0;
+    let mut code = 0;
+    let mut links = Vec::new();
+
+    for caps in token.captures_iter(html) {
+        if let Some(text) = caps.name("text") {
+            // Line breaks between the tags are only kept in code blocks.
+            if !in_pre && text.as_str().trim().is_empty() && text.as_str().contains('\n') {
+                continue;
+            }
+            let text = unescape_html(text.as_str());
+            let text = match format {
+                Format::Markdown => text,
+                Format::Terminal if in_heading => text.bold().green().to_string(),
+                Format::Terminal if emphasis > 0 => text.bold().bright_white().to_string(),
+                Format::Terminal if code > 0 => text.cyan().to_string(),
+                Format::Terminal => text,
+            };
+            output.push_str(&text);
+            continue;
+        }
+
+        let closing = &caps["close"] == "/";
+        match (&caps["tag"], closing, format) {
+            ("h2", false, Format::Markdown) => {
+                in_heading = true;
+                output.push_str("## ");
+            }
+            ("h2", false, Format::Terminal) => in_heading = true,
+            ("h2", true, _) | ("p", true, _) => {
+                in_heading = false;
+                output.push_str("\n\n");
+            }
+            ("li", false, _) => output.push_str("- "),
+            ("li", true, _) | ("ul", true, _) => output.push('\n'),
+            ("pre", false, format) => {
+                in_pre = true;
+                if format == Format::Markdown {
+                    output.push_str("```\n");
+                }
+            }
+            ("pre", true, format) => {
+                in_pre = false;
+                if !output.ends_with('\n') {
+                    output.push('\n');
+                }
+                if format == Format::Markdown {
+                    output.push_str("```\n");
+                }
+                output.push('\n');
+            }
+            ("code", _, format) => {
+                code += if closing { -1 } else { 1 };
+                if !in_pre && format == Format::Markdown {
+                    output.push('`');
+                }
+            }
+            ("em", _, format) => {
+                emphasis += if closing { -1 } else { 1 };
+                if !in_pre && format == Format::Markdown {
+                    output.push('*');
+                }
+            }
+            ("a", false, Format::Markdown) => {
+                links.push(
+                    href.captures(&caps["attributes"])
+                        .map(|caps| caps["href"].to_string()),
+                );
+                output.push('[');
+            }
+            ("a", true, Format::Markdown) => match links.pop().flatten() {
+                Some(href) => output.push_str(&format!("]({href})")),
+                None => output.push(']'),
+            },
+            _ => {}
+        }
+    }
+
+    output.trim_end().to_string() + "\n"
+}
+
+/// First code block with more than one line, or the longest code block if
+/// all of them are on a single line.
+fn example_input(part: &str) -> Option<String> {
+    let re = Regex::new(r"(?s)<pre><code>(?<code>.*?)</code></pre>")
+        .expect("Invalid regex for code blocks");
+    let blocks: Vec<String> = re
+        .captures_iter(part)
+        .map(|caps| strip_tags(&caps["code"]))
+        .collect();
+
+    blocks
+        .iter()
+        .find(|block| block.trim_end().lines().count() > 1)
+        .or_else(|| blocks.iter().max_by_key(|block| block.len()))
+        .cloned()
+}
+
+/// The last emphasized code in a part, which is usually the answer to the example.
+fn last_answer(part: &str) -> Option<String> {
+    let re = Regex::new(r"(?s)<code><em>(?<answer>.*?)</em></code>")
+        .expect("Invalid regex for example answers");
+
+    re.captures_iter(part)
+        .last()
+        .map(|caps| strip_tags(&caps["answer"]).trim().to_string())
+}
+
+fn strip_tags(html: &str) -> String {
+    let re = Regex::new("<[^>]*>").expect("Invalid regex for tags");
+    unescape_html(&re.replace_all(html, ""))
+}
+
+fn unescape_html(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2><p>Something is <em>wrong</em> with <a href="/2023/about">global</a> snow.</p>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+</code></pre>
+<ul><li>The value is <code>12</code>.</li></ul>
+<p>What is the sum &gt; 0?</p>
+</article>
+<p>Your puzzle answer was <code>54388</code>.</p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Digits are spelled out.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn extract_parts() {
+        let description = Description::from_html(PAGE).unwrap();
+
+        assert!(description.has_part_two());
+        assert_eq!(
+            Description::from_html(&description.to_html()),
+            Some(description)
+        );
+        assert_eq!(Description::from_html("<main></main>"), None);
+    }
+
+    #[test]
+    fn render_markdown() {
+        let description = Description::from_html(PAGE).unwrap();
+
+        assert_eq!(
+            description.render(Format::Markdown),
+            r#"## --- Day 1: Trebuchet?! ---
+
+Something is *wrong* with [global](/2023/about) snow.
+
+For example:
+
+```
+1abc2
+pqr3stu8vwx
+```
+
+- The value is `12`.
+
+What is the sum > 0?
+
+## --- Part Two ---
+
+Digits are spelled out.
+"#
+        );
+    }
+
+    #[test]
+    fn examples_with_own_input_for_each_part() {
+        let description = Description::from_html(
+            r#"<article class="day-desc"><p>For example:</p><pre><code>1abc2
+a1b2c3d4e5f
+</code></pre><p>Adding these together produces <code><em>142</em></code>.</p></article>
+<article class="day-desc"><p>For example:</p><pre><code>two1nine
+eightwothree
+</code></pre><p>Adding these together produces <code><em>281</em></code>.</p></article>"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            description.examples(),
+            vec![
+                ExampleCandidate {
+                    input: "1abc2\na1b2c3d4e5f\n".to_string(),
+                    answer_a: Some("142".to_string()),
+                    answer_b: None,
+                },
+                ExampleCandidate {
+                    input: "two1nine\neightwothree\n".to_string(),
+                    answer_a: None,
+                    answer_b: Some("281".to_string()),
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn examples_with_shared_input() {
+        let description = Description::from_html(
+            r#"<article class="day-desc"><pre><code>x</code></pre><p>For example:</p><pre><code>1 &lt; 2
+<em>3</em>
+</code></pre><p>This is <code><em>4</em></code>, the total is <code><em>10</em></code>.</p></article>
+<article class="day-desc"><p>Now it is <code><em>20</em></code>.</p></article>"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            description.examples(),
+            vec![ExampleCandidate {
+                input: "1 < 2\n3\n".to_string(),
+                answer_a: Some("10".to_string()),
+                answer_b: Some("20".to_string()),
+            }]
+        );
+    }
+}
@@ -0,0 +1,82 @@
+use std::{io, path::PathBuf, time::Duration};
+
+use reqwest::StatusCode;
+
+use crate::{
+    cache::{cache_key_path, CACHE_KEY_NAME},
+    format_hms, Problem, Year, TOKEN_NAME,
+};
+
+/// Errors that can occur when interacting with Advent of Code.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum Error {
+    /// No session token was provided, and it was not found in the environment.
+    #[error("Session token to authenticate against Advent of Code was not found. It should be an environment variable named '{TOKEN_NAME}'")]
+    MissingToken,
+    /// The session token contains characters that are not allowed in a cookie.
+    #[error("Session token contains invalid characters")]
+    InvalidToken,
+    /// The session token is invalid or has expired.
+    #[error("Not logged in. The session token is invalid or has expired")]
+    Unauthorized,
+    /// The puzzle is not available yet. See [Problem::unlock_time].
+    #[error("{problem} has not been unlocked yet. It unlocks in {}", format_hms(.remaining))]
+    PuzzleNotUnlocked {
+        problem: Problem,
+        remaining: Duration,
+    },
+    /// The input is not cached, and it cannot be downloaded, e.g. in offline mode.
+    #[error("Input for {0} is not cached, and it cannot be downloaded when offline")]
+    InputNotCached(Problem),
+    /// The input is not a puzzle input, e.g. an error page, or a cached input
+    /// is corrupted.
+    #[error("Input for {problem} is not valid: {reason}")]
+    InvalidInput { problem: Problem, reason: String },
+    /// No key was found for the encrypted bundle with inputs.
+    #[error("Key for the encrypted inputs was not found. It should be an environment variable named '{CACHE_KEY_NAME}', or in the file {}", cache_key_path().display())]
+    MissingCacheKey,
+    /// The encrypted bundle with inputs could not be decrypted.
+    #[error("Unable to decrypt {}. The key is wrong, or the file is corrupted", .0.display())]
+    InvalidBundle(PathBuf),
+    /// A year can only be finished once all other stars of the year are collected.
+    #[error("Advent of Code {} can only be finished with all other 49 stars, but {missing} are missing. Use `aoc sync {}` if they were collected elsewhere", .year.as_int(), .year.as_int())]
+    MissingStars { year: Year, missing: usize },
+    /// A request to Advent of Code was needed, but the client is offline.
+    #[error("Advent of Code cannot be reached in offline mode")]
+    Offline,
+    /// Advent of Code responded with an unsuccessful status code.
+    #[error("Request failed with status code {0}")]
+    Http(StatusCode),
+    /// The request could not be sent, or the response could not be read.
+    #[error("Request to Advent of Code failed: {0}")]
+    Request(#[from] reqwest::Error),
+    /// Reading or writing a file in the data directory failed.
+    #[error("Failed to access the local cache: {0}")]
+    Cache(#[from] io::Error),
+    /// The response from Advent of Code could not be understood.
+    #[error("Unexpected response from Advent of Code:\n\n{0}")]
+    UnexpectedResponse(String),
+    /// The config file with profiles could not be parsed.
+    #[error("Unable to parse config file {}: {source}", path.display())]
+    InvalidConfig {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    /// The profile is not in the config file.
+    #[error("Profile '{0}' was not found in the config file")]
+    UnknownProfile(String),
+    /// The profile name cannot be used as a directory name, e.g. as it
+    /// contains a path separator or `..`.
+    #[error("Profile name '{0}' is not valid. It must not contain path separators or '..'")]
+    InvalidProfileName(String),
+    /// A file with stars or answers in the data directory could not be parsed.
+    #[error("Unable to parse {}: {source}", path.display())]
+    CorruptStateFile {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+}
+
+/// Result type for the client, with [Error] as the default error.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
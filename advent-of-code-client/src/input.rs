@@ -0,0 +1,150 @@
+//! Sources of puzzle inputs, so solutions can be run without access to
+//! Advent of Code, e.g. in CI or on machines without a token.
+use std::{collections::HashMap, env, path::PathBuf};
+
+use crate::{cache, AocClient, AocClientBuilder, Error, Problem, Result};
+
+/// Environment variable to enable offline mode.
+const OFFLINE_NAME: &str = "AOC_OFFLINE";
+
+/// Source of the input for a problem.
+pub trait InputProvider {
+    /// Get the input for a problem.
+    fn get_input(&self, problem: Problem) -> Result<String>;
+}
+
+impl InputProvider for AocClient {
+    /// Get the input from the cache, or download it from Advent of Code.
+    fn get_input(&self, problem: Problem) -> Result<String> {
+        AocClient::get_input(self, problem)
+    }
+}
+
+/// Inputs from the local cache only, or the encrypted bundle in the data
+/// directory, e.g. when no token is available. Nothing is ever downloaded, and
+/// inputs that are not cached give
+/// [Error::InputNotCached]. Cached inputs that are corrupted give [Error::InvalidInput].
+#[derive(Debug, Clone)]
+pub struct CachedInputs {
+    data_dir: PathBuf,
+}
+
+impl CachedInputs {
+    /// Read inputs cached in `data_dir`.
+    pub fn new(data_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            data_dir: data_dir.into(),
+        }
+    }
+}
+
+impl InputProvider for CachedInputs {
+    fn get_input(&self, problem: Problem) -> Result<String> {
+        let status = match cache::read_input_from_cache(&self.data_dir, problem)? {
+            Some(input) => match cache::check_cached_input(&self.data_dir, problem, &input) {
+                status if status.is_usable() => return Ok(input),
+                status => Some(status),
+            },
+            None => None,
+        };
+
+        match (
+            cache::read_input_from_bundle(&self.data_dir, problem)?,
+            status,
+        ) {
+            (Some(input), _) => Ok(input),
+            (None, Some(status)) => Err(Error::InvalidInput {
+                problem,
+                reason: status.to_string(),
+            }),
+            (None, None) => Err(Error::InputNotCached(problem)),
+        }
+    }
+}
+
+/// Inputs kept in memory, e.g. to test solutions without any files.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryInputs {
+    inputs: HashMap<Problem, String>,
+}
+
+impl MemoryInputs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the input for a problem.
+    pub fn with_input(mut self, problem: Problem, input: impl Into<String>) -> Self {
+        self.insert(problem, input);
+        self
+    }
+
+    /// Add the input for a problem, replacing any existing input.
+    pub fn insert(&mut self, problem: Problem, input: impl Into<String>) {
+        self.inputs.insert(problem, input.into());
+    }
+}
+
+impl InputProvider for MemoryInputs {
+    fn get_input(&self, problem: Problem) -> Result<String> {
+        self.inputs
+            .get(&problem)
+            .cloned()
+            .ok_or(Error::InputNotCached(problem))
+    }
+}
+
+/// Provider for inputs that uses the client, or only cached inputs if no
+/// token is available. In offline mode the client only uses cached inputs too.
+pub fn default_input_provider() -> Result<Box<dyn InputProvider>> {
+    input_provider(AocClient::builder())
+}
+
+/// Like [default_input_provider], but with the client built from `builder`,
+/// e.g. to use a profile or offline mode.
+pub fn input_provider(builder: AocClientBuilder) -> Result<Box<dyn InputProvider>> {
+    let data_dir = builder.resolved_data_dir()?;
+    match builder.build() {
+        Ok(client) => Ok(Box::new(client)),
+        Err(Error::MissingToken) => Ok(Box::new(CachedInputs::new(data_dir))),
+        Err(e) => Err(e),
+    }
+}
+
+/// Whether offline mode is enabled by setting `AOC_OFFLINE` to anything
+/// other than an empty string, `0`, or `false`.
+pub(crate) fn is_offline_from_env() -> bool {
+    env::var(OFFLINE_NAME).is_ok_and(|value| !matches!(value.as_str(), "" | "0" | "false"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_utils::temp_data_dir, Year};
+
+    #[test]
+    fn memory_inputs() {
+        let problem = Problem::new(Year::Y2023, 1);
+        let inputs = MemoryInputs::new().with_input(problem, "1abc2");
+
+        assert_eq!(inputs.get_input(problem).unwrap(), "1abc2");
+        assert!(matches!(
+            inputs.get_input(Problem::new(Year::Y2023, 2)),
+            Err(Error::InputNotCached(_))
+        ));
+    }
+
+    #[test]
+    fn cached_inputs() {
+        let data_dir = temp_data_dir();
+        let problem = Problem::new(Year::Y2023, 1);
+        cache::store_input_in_cache(data_dir.path(), problem, "1abc2").unwrap();
+        let inputs = CachedInputs::new(data_dir.path());
+
+        assert_eq!(inputs.get_input(problem).unwrap(), "1abc2");
+        assert!(matches!(
+            inputs.get_input(Problem::new(Year::Y2023, 2)),
+            Err(Error::InputNotCached(_))
+        ));
+    }
+}
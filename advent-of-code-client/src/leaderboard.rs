@@ -0,0 +1,118 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::Deserialize;
+
+use crate::{Day, Level};
+
+/// Private leaderboard, as returned by the JSON endpoint of Advent of Code.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    pub owner_id: u64,
+    /// Members of the leaderboard by their id.
+    pub members: HashMap<String, Member>,
+}
+
+impl Leaderboard {
+    /// Members sorted by their local score, highest first. Ties are broken
+    /// by who got their last star first.
+    pub fn members_by_score(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.values().collect();
+        members.sort_by_key(|member| {
+            (
+                std::cmp::Reverse(member.local_score),
+                member.last_star_ts,
+                member.id,
+            )
+        });
+        members
+    }
+}
+
+/// Member of a private leaderboard.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    /// Name of the member. Anonymous users do not have a name.
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    pub global_score: u32,
+    /// Time of the last star in seconds since the Unix epoch, or 0 if the
+    /// member has no stars.
+    pub last_star_ts: u64,
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<Day, DayCompletion>,
+}
+
+impl Member {
+    /// Name of the member, the same way as Advent of Code shows anonymous users.
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    /// The star for a given day and level, if the member has it.
+    pub fn star(&self, day: Day, level: Level) -> Option<&Star> {
+        let completion = self.completion_day_level.get(&day)?;
+        match level {
+            Level::A => completion.a.as_ref(),
+            Level::B => completion.b.as_ref(),
+        }
+    }
+}
+
+/// Stars a member has for a day.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct DayCompletion {
+    #[serde(rename = "1")]
+    pub a: Option<Star>,
+    #[serde(rename = "2", default)]
+    pub b: Option<Star>,
+}
+
+/// A star for completing a level.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Star {
+    /// Time the star was given in seconds since the Unix epoch.
+    #[serde(rename = "get_star_ts")]
+    pub timestamp: u64,
+    pub star_index: u64,
+}
+
+impl Star {
+    /// Time the star was given.
+    pub fn time(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.timestamp)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const LEADERBOARD: &str = include_str!("../data/leaderboard.json");
+
+    #[test]
+    fn deserialize_leaderboard() {
+        let leaderboard: Leaderboard = serde_json::from_str(LEADERBOARD).unwrap();
+
+        assert_eq!(leaderboard.event, "2023");
+        let members = leaderboard.members_by_score();
+        assert_eq!(
+            members.iter().map(|m| m.display_name()).collect::<Vec<_>>(),
+            vec!["Alice", "(anonymous user #3)", "Bob"]
+        );
+
+        let alice = members[0];
+        assert_eq!(alice.stars, 3);
+        assert_eq!(alice.star(1, Level::A).unwrap().timestamp, 1701407100);
+        assert!(alice.star(2, Level::A).is_some());
+        assert!(alice.star(2, Level::B).is_none());
+        assert!(alice.star(3, Level::A).is_none());
+    }
+}
@@ -7,31 +7,90 @@
 //! This will install the `aoc` client that can be used to submit answers.
 //!
 //! The main interface is through [AocClient], which provides a [AocClient::get_input]
-//! function to retreive your personalized input for a puzzle, and [AocClient::submit]
-//! to submit an answer for a given [Problem] and [Level].
+//! function to retreive your personalized input for a puzzle, [AocClient::get_description]
+//! to read the puzzle, and [AocClient::submit] to submit an answer for a given
+//! [Problem] and [Level].
 //!
 //! ## Authentication
 //!
 //! See [crate README](https://github.com/OliverFlecke/advent-of-code-rust/tree/main/advent-of-code-client/README.md#authentication)
 //! for details on getting your personal token.
+//! Tokens for several accounts can be stored as named profiles in a [Config]
+//! file, and selected with [AocClientBuilder::profile].
 //!
-use std::{env, fmt::Display, fs, time::Duration};
+//! ## Data directory
+//!
+//! Inputs, descriptions, stars, and answers are stored in a data directory,
+//! which is found by [default_data_dir], or can be set with [AocClient::with_data_dir].
+//!
+//! ## Offline mode
+//!
+//! With [AocClientBuilder::offline], or `AOC_OFFLINE` set, the client never
+//! sends requests and only uses cached data. Inputs can also be provided
+//! without a client through an [InputProvider], e.g. [CachedInputs] or [MemoryInputs].
+//!
+//! ## Encrypted inputs
+//!
+//! Inputs can be shared in an encrypted bundle with [export_bundle] and
+//! [import_bundle]. As inputs differ between accounts, a bundle holds the
+//! inputs of a single account. With a [CacheKey] set, inputs that are not
+//! cached are read from the bundle at [default_bundle_path] when offline or
+//! without a token, but never instead of downloading the input of the user.
+//!
+//! ## Errors
+//!
+//! All fallible functions return an [Error], so cases like a missing or
+//! expired token, a puzzle that is not unlocked yet, or a corrupt file in the
+//! data directory can be handled separately.
+//!
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    thread,
+    time::Duration,
+};
 
-use anyhow::Context;
 use colored::Colorize;
 use reqwest::{
     blocking::{Client, Response},
-    header::{HeaderMap, HeaderValue, COOKIE},
-    Url,
+    StatusCode, Url,
 };
 
 use crate::score::ScoreMap;
 
+mod answers;
+mod builder;
 mod cache;
+mod data_dir;
+mod description;
+mod error;
+mod input;
+mod leaderboard;
 mod problem;
+mod profile;
+mod progress;
 mod score;
+mod throttle;
 
+pub use answers::{AnswerMap, Hint, KnownIncorrect};
+pub use builder::{AocClientBuilder, RetryPolicy};
+pub use cache::{
+    cache_key_path, default_bundle_path, export_bundle, import_bundle, verify_cached_inputs,
+    CacheKey, InputStatus,
+};
+pub use data_dir::default_data_dir;
+pub use description::{Description, ExampleCandidate, Format};
+pub use error::{Error, Result};
+pub use input::{
+    default_input_provider, input_provider, CachedInputs, InputProvider, MemoryInputs,
+};
+pub use leaderboard::{DayCompletion, Leaderboard, Member, Star};
 pub use problem::{Day, Level, Problem, Year};
+pub use profile::{config_path, profile_data_dir, Config, Profile};
+pub use progress::{render_dashboard, DayProgress, YearProgress};
 
 const TOKEN_NAME: &str = "AOC_TOKEN";
 
@@ -49,46 +108,256 @@ const TOKEN_NAME: &str = "AOC_TOKEN";
 /// AocClient::default();
 ///
 /// AocClient::from_token("your personal session token".to_string());
+///
+/// // Use the builder for more control over the client.
+/// AocClient::builder()
+///     .token("your personal session token")
+///     .user_agent_contact("you@example.com")
+///     .build()
+///     .unwrap();
 /// ````
 #[derive(Debug)]
 pub struct AocClient {
     base_url: Url,
     http_client: Client,
+    retry_policy: RetryPolicy,
+    data_dir: PathBuf,
+    wait_when_too_recent: bool,
+    wait_for_unlock: bool,
+    offline: bool,
 }
 
 impl Default for AocClient {
+    /// Create a client with the token from `AOC_TOKEN`. Panics if it is
+    /// missing, use [AocClient::builder] to handle this as an error.
     fn default() -> Self {
-        Self::new(default_url_for_advent_of_code(), get_token())
+        AocClientBuilder::new()
+            .build()
+            .unwrap_or_else(|e| panic!("{e}"))
     }
 }
 
 impl AocClient {
     /// Create a new client to interact with Advent of Code.
     fn new(base_url: Url, aoc_token: String) -> Self {
-        let http_client = Self::build_client(&aoc_token);
+        AocClientBuilder::new()
+            .base_url(base_url)
+            .token(aoc_token)
+            .build()
+            .expect("Failed to create client")
+    }
+
+    /// Create a new client from a AoC session token. Panics if the token
+    /// contains invalid characters.
+    pub fn from_token(aoc_token: String) -> Self {
+        Self::new(default_url_for_advent_of_code(), aoc_token)
+    }
+
+    /// Builder to configure the client, e.g. with another base URL or timeout.
+    pub fn builder() -> AocClientBuilder {
+        AocClientBuilder::new()
+    }
+
+    /// Store inputs, descriptions, stars, and answers in `data_dir`, instead
+    /// of the directory given by [default_data_dir].
+    pub fn with_data_dir(mut self, data_dir: impl Into<PathBuf>) -> Self {
+        self.data_dir = data_dir.into();
+        self
+    }
+
+    /// Directory where inputs, descriptions, stars, and answers are stored.
+    pub fn data_dir(&self) -> &Path {
+        &self.data_dir
+    }
+
+    /// Wait and submit again when an answer is rejected for being submitted
+    /// too recently, instead of returning [SubmissionResult::TooRecent].
+    /// A countdown is shown while waiting.
+    pub fn wait_when_too_recent(mut self, wait: bool) -> Self {
+        self.wait_when_too_recent = wait;
+        self
+    }
+
+    /// Wait for a puzzle to unlock when getting its input or description,
+    /// instead of failing with [Error::PuzzleNotUnlocked].
+    /// A countdown is shown while waiting.
+    pub fn wait_for_unlock(mut self, wait: bool) -> Self {
+        self.wait_for_unlock = wait;
+        self
+    }
 
-        AocClient {
-            base_url,
-            http_client,
+    /// Get the personal input for a user for a given problem.
+    ///
+    /// Inputs are cached, and a cached input that does not match its checksum
+    /// or is not a puzzle input, e.g. an error page, is downloaded again.
+    /// In offline mode, inputs that are not cached are read from the encrypted
+    /// bundle in the data directory if a key is set, see [CacheKey]. Otherwise
+    /// they are downloaded, as the bundle might have the inputs of another account.
+    pub fn get_input(&self, problem: Problem) -> Result<String> {
+        let status = match cache::read_input_from_cache(&self.data_dir, problem)? {
+            Some(input) => match cache::check_cached_input(&self.data_dir, problem, &input) {
+                status if status.is_usable() => return Ok(input),
+                status => Some(status),
+            },
+            None => None,
+        };
+        if self.offline {
+            if let Some(input) = cache::read_input_from_bundle(&self.data_dir, problem)? {
+                return Ok(input);
+            }
+        }
+
+        match status {
+            Some(status) if self.offline => Err(Error::InvalidInput {
+                problem,
+                reason: status.to_string(),
+            }),
+            None if self.offline => Err(Error::InputNotCached(problem)),
+            _ => self.refetch_input(problem),
         }
     }
 
-    /// Create a new client from a AoC session token.
-    pub fn from_token(aoc_token: String) -> Self {
-        Self {
-            base_url: default_url_for_advent_of_code(),
-            http_client: Self::build_client(&aoc_token),
+    /// Download the input for a problem and replace the cached input, e.g.
+    /// if it is corrupted. The input is validated before it is cached.
+    pub fn refetch_input(&self, problem: Problem) -> Result<String> {
+        self.ensure_unlocked(problem)?;
+        let input = cache::normalize_input(self.download_input(problem)?);
+        cache::validate_input(&input).map_err(|reason| Error::InvalidInput { problem, reason })?;
+        cache::store_input_in_cache(&self.data_dir, problem, &input)?;
+
+        Ok(input)
+    }
+
+    /// Get the description of a problem.
+    ///
+    /// The description is cached next to the input. As the second part is only
+    /// included once the first part is solved, a cached description without it
+    /// is downloaded again when the first part is known to be solved.
+    pub fn get_description(&self, problem: Problem) -> Result<Description> {
+        let cached = fs::read_to_string(cache::get_description_cache_full_filename(
+            &self.data_dir,
+            problem,
+        ))
+        .ok()
+        .and_then(|html| Description::from_html(&html));
+        let part_one_solved = ScoreMap::load(&self.data_dir, *problem.year())?
+            .get_score_for_day(*problem.day())
+            .is_some();
+
+        match cached {
+            Some(description) if description.has_part_two() || !part_one_solved || self.offline => {
+                Ok(description)
+            }
+            _ => {
+                self.ensure_unlocked(problem)?;
+                let page = self.get_puzzle_page(problem)?;
+                let description =
+                    Description::from_html(&page).ok_or(Error::UnexpectedResponse(page))?;
+                cache::store_description_in_cache(&self.data_dir, problem, &description.to_html())?;
+                Ok(description)
+            }
         }
     }
 
-    /// Get the personal input for a user for a given problem.
-    pub fn get_input(&self, problem: Problem) -> anyhow::Result<String> {
-        match fs::read_to_string(cache::get_input_cache_full_filename(problem)) {
-            Ok(content) => Ok(content),
-            Err(_) => {
-                let input = self.download_input(problem)?;
-                cache::store_input_in_cache(problem, &input)?;
-                Ok(input)
+    /// Get a private leaderboard for a year. The `id` is the number at the end
+    /// of the URL of the leaderboard, which is also the user id of its owner.
+    /// Fails with [Error::UnexpectedResponse] if you do not have access to it.
+    pub fn private_leaderboard(&self, year: Year, id: u64) -> Result<Leaderboard> {
+        let url = self
+            .base_url
+            .join(&format!(
+                "{year}/leaderboard/private/view/{id}.json",
+                year = year.as_int()
+            ))
+            .expect("Failed to create URL for leaderboard");
+
+        let body = self.get_text(url, None)?;
+        serde_json::from_str(&body).map_err(|_| Error::UnexpectedResponse(body))
+    }
+
+    /// Update the locally tracked stars for a year with the stars shown in
+    /// the calendar on Advent of Code, so problems solved on other machines
+    /// are not submitted again. Stars are only ever added.
+    ///
+    /// Returns the number of days that were updated.
+    pub fn sync_stars(&self, year: Year) -> Result<usize> {
+        let url = self
+            .base_url
+            .join(&format!("{year}/", year = year.as_int()))
+            .expect("Failed to create URL for calendar");
+        let calendar = self.get_text(url, None)?;
+
+        ScoreMap::load(&self.data_dir, year)?.merge(score::stars_from_calendar(&calendar))
+    }
+
+    /// Get the name of the user the session token belongs to, as shown on
+    /// Advent of Code. Fails with [Error::Unauthorized] if the token is
+    /// invalid or has expired.
+    pub fn whoami(&self) -> Result<String> {
+        let url = self
+            .base_url
+            .join("settings")
+            .expect("Failed to create URL for settings");
+        let page = self.get_text(url, None)?;
+
+        user_name_from_page(&page).ok_or(Error::Unauthorized)
+    }
+
+    /// Fail if the puzzle is not unlocked yet, or wait until it is if
+    /// [AocClient::wait_for_unlock] is enabled.
+    fn ensure_unlocked(&self, problem: Problem) -> Result<()> {
+        match problem.time_until_unlock() {
+            Some(remaining) if self.wait_for_unlock => {
+                countdown(remaining, &format!("fetching {problem}"));
+                Ok(())
+            }
+            Some(remaining) => Err(Error::PuzzleNotUnlocked { problem, remaining }),
+            None => Ok(()),
+        }
+    }
+
+    /// Get the HTML page with the puzzle description for a problem. The second
+    /// part of the description is only included once the first part is solved.
+    fn get_puzzle_page(&self, problem: Problem) -> Result<String> {
+        self.get_text(self.get_base_url_for_problem(problem), Some(problem))
+    }
+
+    /// Send a HTTP GET request and get the body of the response. If the
+    /// request is for a `problem`, a missing page means it is not unlocked yet.
+    fn get_text(&self, url: Url, problem: Option<Problem>) -> Result<String> {
+        let response = self.get_with_retries(url)?;
+        match (response.status(), problem) {
+            (status, _) if status.is_success() => Ok(response.text()?),
+            (StatusCode::BAD_REQUEST | StatusCode::UNAUTHORIZED, _) => Err(Error::Unauthorized),
+            (StatusCode::NOT_FOUND, Some(problem)) => Err(Error::PuzzleNotUnlocked {
+                problem,
+                remaining: problem.time_until_unlock().unwrap_or_default(),
+            }),
+            (status, _) => Err(Error::Http(status)),
+        }
+    }
+
+    /// Send a HTTP GET request, retrying on network and server errors as
+    /// given by the retry policy.
+    fn get_with_retries(&self, url: Url) -> Result<Response> {
+        if self.offline {
+            return Err(Error::Offline);
+        }
+
+        let mut retry = 0;
+        loop {
+            let result = self.http_client.get(url.clone()).send();
+            let failed = match &result {
+                Ok(response) => response.status().is_server_error(),
+                Err(e) => e.is_timeout() || e.is_connect() || e.is_request(),
+            };
+
+            match self.retry_policy.backoff(retry) {
+                Some(backoff) if failed => {
+                    thread::sleep(backoff);
+                    retry += 1;
+                }
+                _ => return Ok(result?),
             }
         }
     }
@@ -97,15 +366,31 @@ impl AocClient {
     ///
     /// This will **not** resubmit the answer if the problem has already been
     /// solved from this machine. To track this, the status for each puzzle is
-    /// tracked in `./stars` directory. In this case a
+    /// tracked in the `.stars` directory in the [AocClient::data_dir]. In this case a
     /// [SubmissionResult::SkippingAlreadyCompleted] is returned.
+    ///
+    /// Every answer is recorded in the [AnswerMap] for the year. An answer that
+    /// is already known to be incorrect, either because it has been rejected
+    /// before or because it is out of range of earlier too high/too low hints,
+    /// is not submitted, and [SubmissionResult::SkippingKnownIncorrect] is returned.
+    ///
+    /// The time until the next answer can be submitted is tracked locally, and
+    /// no request is sent before then. Unless [AocClient::wait_when_too_recent]
+    /// is enabled, [SubmissionResult::TooRecent] is returned right away.
+    ///
+    /// Day 25 has no part two, so submitting level B for it ignores the answer
+    /// and finishes the year instead, see [AocClient::finish].
     pub fn submit(
         &self,
         problem: Problem,
         level: Level,
         answer: &String,
-    ) -> anyhow::Result<SubmissionResult> {
-        let mut scores = ScoreMap::load(*problem.year());
+    ) -> Result<SubmissionResult> {
+        if level == Level::B && !problem.has_part_two() {
+            return self.finish(*problem.year());
+        }
+
+        let mut scores = ScoreMap::load(&self.data_dir, *problem.year())?;
 
         // Check if problem is already solved.
         if scores
@@ -116,12 +401,46 @@ impl AocClient {
             return Ok(SubmissionResult::SkippingAlreadyCompleted);
         }
 
-        let response = self.post_answer(problem, level, answer);
-        let result = response.map(|x| x.try_into())??;
+        let mut answers = AnswerMap::load(&self.data_dir, *problem.year())?;
+        if let Some(reason) = answers.check_incorrect(*problem.day(), level, answer) {
+            return Ok(SubmissionResult::SkippingKnownIncorrect(reason));
+        }
+
+        if let Some(remaining) = throttle::remaining(&self.data_dir) {
+            if !self.wait_when_too_recent {
+                return Ok(SubmissionResult::TooRecent(remaining));
+            }
+            countdown(remaining, "submitting answer");
+        }
+
+        let mut result = self.post_and_parse_answer(problem, level, answer)?;
+        if let SubmissionResult::TooRecent(duration) = result {
+            if self.wait_when_too_recent {
+                countdown(duration, "submitting answer");
+                result = self.post_and_parse_answer(problem, level, answer)?;
+            }
+        }
 
         match result {
-            SubmissionResult::Correct | SubmissionResult::AlreadyCompleted => {
-                scores.set_score_for_day(*problem.day(), &level);
+            SubmissionResult::Correct => {
+                scores.set_score_for_day(*problem.day(), &level)?;
+                answers.set_answer(*problem.day(), level, answer)?;
+            }
+            // Part A is always available, so it must already be solved. The same
+            // goes for part B when part A is known to be solved.
+            SubmissionResult::WrongLevel
+                if level == Level::A || scores.get_score_for_day(*problem.day()).is_some() =>
+            {
+                scores.set_score_for_day(*problem.day(), &level)?;
+            }
+            SubmissionResult::Incorrect(_) => {
+                answers.add_incorrect(*problem.day(), level, answer, None)?;
+            }
+            SubmissionResult::TooHigh(_) => {
+                answers.add_incorrect(*problem.day(), level, answer, Some(Hint::TooHigh))?;
+            }
+            SubmissionResult::TooLow(_) => {
+                answers.add_incorrect(*problem.day(), level, answer, Some(Hint::TooLow))?;
             }
             _ => {}
         }
@@ -129,17 +448,63 @@ impl AocClient {
         Ok(result)
     }
 
-    /// Send a HTTP POST request with the answer for the problem at a given year,
-    /// day, and level. The answer must always be provided as a string.
-    fn post_answer(
+    /// Finish Advent of Code for a year, which gives the second star of day 25.
+    /// This is only possible when all other stars of the year are collected,
+    /// so it fails with [Error::MissingStars] if any are missing from the
+    /// stored stars, without sending anything.
+    pub fn finish(&self, year: Year) -> Result<SubmissionResult> {
+        let problem = Problem::new(year, 25);
+        let mut scores = ScoreMap::load(&self.data_dir, year)?;
+        if scores.get_score_for_day(*problem.day()) == Some(Level::B) {
+            return Ok(SubmissionResult::SkippingAlreadyCompleted);
+        }
+        let missing: usize = (1..=25)
+            .map(|day| match (day, scores.get_score_for_day(day)) {
+                (_, Some(Level::B)) | (25, Some(Level::A)) => 0,
+                (25, None) | (_, Some(Level::A)) => 1,
+                (_, None) => 2,
+            })
+            .sum();
+        if missing > 0 {
+            return Err(Error::MissingStars { year, missing });
+        }
+
+        println!("Finishing Advent of Code {}", year.as_int());
+        // The form to finish has a fixed answer, which is never checked.
+        let result = SubmissionResult::try_from(self.post_answer(problem, Level::B, "0")?)?;
+        if result == SubmissionResult::Finished {
+            scores.set_score_for_day(*problem.day(), &Level::B)?;
+        }
+
+        Ok(result)
+    }
+
+    /// Submit the answer and parse the response, recording how long to wait
+    /// before the next submission.
+    fn post_and_parse_answer(
         &self,
         problem: Problem,
         level: Level,
         answer: &String,
-    ) -> Result<Response, reqwest::Error> {
+    ) -> Result<SubmissionResult> {
         println!("Submitting answer for {problem}/{level:?} is: {answer}");
+        let result = SubmissionResult::try_from(self.post_answer(problem, level, answer)?)?;
+        if let Some(wait) = result.wait_time() {
+            throttle::record(&self.data_dir, wait)?;
+        }
 
-        self.http_client
+        Ok(result)
+    }
+
+    /// Send a HTTP POST request with the answer for the problem at a given year,
+    /// day, and level. The answer must always be provided as a string.
+    fn post_answer(&self, problem: Problem, level: Level, answer: &str) -> Result<Response> {
+        if self.offline {
+            return Err(Error::Offline);
+        }
+
+        Ok(self
+            .http_client
             .post(
                 self.get_base_url_for_problem(problem)
                     .join("answer")
@@ -149,27 +514,17 @@ impl AocClient {
                 ("level", level.as_int().to_string()),
                 ("answer", answer.to_string()),
             ])
-            .send()
+            .send()?)
     }
 
     /// Download the input for a given problem.
-    fn download_input(&self, problem: Problem) -> anyhow::Result<String> {
+    fn download_input(&self, problem: Problem) -> Result<String> {
         let url = self
             .get_base_url_for_problem(problem)
             .join("input")
             .expect("Failed to create download URL for `input`");
 
-        match self.http_client.get(url).send() {
-            Ok(response) if response.status().is_success() => {
-                response.text().context("Failed to read response body")
-            }
-            Ok(response) => Err(anyhow::anyhow!(
-                "Invalid status code: {}. Message from server:\n{}",
-                response.status(),
-                response.text().unwrap()
-            )),
-            Err(e) => Err(anyhow::anyhow!("Request failed to download input: {e:?}")),
-        }
+        self.get_text(url, Some(problem))
     }
 
     /// Get the base url for a problem.
@@ -182,46 +537,78 @@ impl AocClient {
             ))
             .expect("Failed to create URL for problem")
     }
-
-    /// Build a HTTP client to send request to Advent of Code.
-    fn build_client(token: &str) -> Client {
-        reqwest::blocking::Client::builder()
-            .default_headers({
-                let mut headers = HeaderMap::new();
-                headers.insert(
-                    COOKIE,
-                    HeaderValue::from_str(&format!("session={token}"))
-                        .expect("Failed to make header value with token"),
-                );
-                headers
-            })
-            .user_agent("github.com/OliverFlecke/advent-of-code-rust by oliverfl@live.dk")
-            .build()
-            .expect("Failed to create reqwest client")
-    }
 }
 
 fn default_url_for_advent_of_code() -> Url {
     Url::parse("https://adventofcode.com/").expect("Failed to create URL for AoC")
 }
 
-/// Read the token required to authenticate against the Advent of Code server.
-/// Panics if it cannot be found.
-fn get_token() -> String {
-    match env::var(TOKEN_NAME) {
-        Ok(token) => token,
-        Err(_) => panic!("Session token to authenticate against advent of code was not found. It should be an environment variable named 'AOC_TOKEN'"),
+/// Block for `duration`, while showing the remaining time before `what`.
+fn countdown(duration: Duration, what: &str) {
+    let mut remaining = duration.as_secs() + u64::from(duration.subsec_nanos() > 0);
+    while remaining > 0 {
+        print!(
+            "\r{}",
+            format!(
+                "Waiting {} before {what}... ",
+                format_hms(&Duration::from_secs(remaining))
+            )
+            .yellow()
+        );
+        io::stdout().flush().ok();
+        thread::sleep(Duration::from_secs(1));
+        remaining -= 1;
     }
+    println!();
+}
+
+/// Format a duration as `HH:MM:SS`, rounding up to whole seconds.
+fn format_hms(duration: &Duration) -> String {
+    let seconds = duration.as_secs() + u64::from(duration.subsec_nanos() > 0);
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+/// Read the token required to authenticate against the Advent of Code server.
+fn get_token() -> Result<String> {
+    env::var(TOKEN_NAME).map_err(|_| Error::MissingToken)
 }
 
 /// Result of a submission of an answer to a problem.
+///
+/// The durations for incorrect answers are the time to wait before another
+/// answer can be submitted.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SubmissionResult {
     Correct,
-    Incorrect,
-    AlreadyCompleted,
+    /// The answer is incorrect, without any hint to whether it was too high or too low.
+    Incorrect(Duration),
+    TooHigh(Duration),
+    TooLow(Duration),
+    /// The level is either already solved or not unlocked yet.
+    WrongLevel,
     SkippingAlreadyCompleted,
+    SkippingKnownIncorrect(KnownIncorrect),
     TooRecent(Duration),
+    /// All puzzles of the year are finished, which gives the second star of day 25.
+    Finished,
+}
+
+impl SubmissionResult {
+    /// Time to wait before another answer can be submitted, if any.
+    pub fn wait_time(&self) -> Option<Duration> {
+        use SubmissionResult::*;
+        match self {
+            Incorrect(duration) | TooHigh(duration) | TooLow(duration) | TooRecent(duration) => {
+                Some(*duration)
+            }
+            _ => None,
+        }
+    }
 }
 
 impl Display for SubmissionResult {
@@ -231,44 +618,93 @@ impl Display for SubmissionResult {
             Correct => {
                 write!(f, "{}", "Answer is correct".green())
             }
-            Incorrect => {
-                write!(f, "{}", "You answered incorrectly!".red())
+            Incorrect(duration) => {
+                write!(
+                    f,
+                    "{} Wait {duration:?} before trying again",
+                    "You answered incorrectly!".red()
+                )
             }
-            AlreadyCompleted => {
+            TooHigh(duration) => {
+                write!(
+                    f,
+                    "{} Wait {duration:?} before trying again",
+                    "Your answer is too high!".red()
+                )
+            }
+            TooLow(duration) => {
+                write!(
+                    f,
+                    "{} Wait {duration:?} before trying again",
+                    "Your answer is too low!".red()
+                )
+            }
+            WrongLevel => {
                 write!(
                     f,
                     "{}",
-                    "Problem already solved, but answer was correct".green()
+                    "Wrong level. The problem is either already solved or not unlocked yet"
+                        .yellow()
                 )
             }
             SkippingAlreadyCompleted => {
                 write!(f, "Problem already solved. Skipping submission")
             }
+            SkippingKnownIncorrect(reason) => {
+                let reason = match reason {
+                    KnownIncorrect::PreviouslySubmitted => {
+                        "it has already been submitted".to_string()
+                    }
+                    KnownIncorrect::TooLow { lower_bound } => {
+                        format!("it is not higher than {lower_bound}, which was too low")
+                    }
+                    KnownIncorrect::TooHigh { upper_bound } => {
+                        format!("it is not lower than {upper_bound}, which was too high")
+                    }
+                };
+                write!(
+                    f,
+                    "{}",
+                    format!("Answer is known to be incorrect, as {reason}. Skipping submission")
+                        .red()
+                )
+            }
             TooRecent(duration) => {
                 write!(
                     f,
                     "You have submitted an answer too recently. Wait a {duration:?} and try again"
                 )
             }
+            Finished => {
+                write!(
+                    f,
+                    "{}",
+                    "Congratulations! You have finished every puzzle of the year".green()
+                )
+            }
         }
     }
 }
 
 impl TryFrom<Response> for SubmissionResult {
-    type Error = anyhow::Error;
+    type Error = Error;
 
-    fn try_from(response: Response) -> Result<Self, Self::Error> {
-        let body = get_main_part_from_html_response(response);
+    fn try_from(response: Response) -> Result<Self> {
+        let body = get_main_part_from_html_response(response.text()?);
 
         response_body_to_submission_result(&body)
     }
 }
 
-fn response_body_to_submission_result(body: &str) -> anyhow::Result<SubmissionResult> {
+/// Parse the result of a submission. The session token being rejected is an
+/// [Error::Unauthorized] rather than a result.
+fn response_body_to_submission_result(body: &str) -> Result<SubmissionResult> {
     if body.contains("That's the right answer") {
         Ok(SubmissionResult::Correct)
-    } else if body.contains("already complete it") {
-        Ok(SubmissionResult::AlreadyCompleted)
+    } else if body.contains("You've finished every puzzle") {
+        Ok(SubmissionResult::Finished)
+    } else if body.contains("solving the right level") {
+        Ok(SubmissionResult::WrongLevel)
     } else if body.contains("answer too recently") {
         use duration_string::DurationString;
 
@@ -289,43 +725,108 @@ fn response_body_to_submission_result(body: &str) -> anyhow::Result<SubmissionRe
         println!("Body: {}", body);
         Ok(SubmissionResult::TooRecent(time))
     } else if body.contains("not the right answer") {
-        println!("Body: {}", body);
-        Ok(SubmissionResult::Incorrect)
+        // Default wait time after a wrong answer is one minute, which increases
+        // after several wrong answers.
+        let wait = incorrect_answer_wait_time(body).unwrap_or(Duration::from_secs(60));
+
+        if body.contains("your answer is too high") {
+            Ok(SubmissionResult::TooHigh(wait))
+        } else if body.contains("your answer is too low") {
+            Ok(SubmissionResult::TooLow(wait))
+        } else {
+            Ok(SubmissionResult::Incorrect(wait))
+        }
+    } else if body.contains("please identify yourself") || body.contains("Please log in") {
+        Err(Error::Unauthorized)
     } else {
-        Err(anyhow::anyhow!("Unknown response:\n\n{}", body))
+        Err(Error::UnexpectedResponse(body.to_string()))
     }
 }
 
+/// Parse the wait time from the response to an incorrect answer, e.g.
+/// "please wait 5 minutes before trying again".
+fn incorrect_answer_wait_time(body: &str) -> Option<Duration> {
+    let re = regex::RegexBuilder::new(
+        r"wait (?<amount>one|\d+) (?<unit>second|minute)s? before trying again",
+    )
+    .case_insensitive(true)
+    .build()
+    .expect("Invalid regex for incorrect answer wait time");
+    let caps = re.captures(body)?;
+    let amount = match &caps["amount"] {
+        "one" => 1,
+        amount => amount.parse().ok()?,
+    };
+    let unit = match &caps["unit"] {
+        "second" => 1,
+        _ => 60,
+    };
+
+    Some(Duration::from_secs(amount * unit))
+}
+
+/// Get the name of the logged in user from the header of a page.
+fn user_name_from_page(page: &str) -> Option<String> {
+    let re = regex::Regex::new(r#"<div class="user">(?<name>[^<]*)"#)
+        .expect("Invalid regex for user name");
+    let name = re.captures(page)?["name"].trim().to_string();
+
+    (!name.is_empty()).then_some(name)
+}
+
 /// This extracts the part of the submission response within the `<main>` tags.
 /// As this contains the primary message from AoC, the rest can be thrown away
 /// when you just want to know whether your answer was right or not.
-fn get_main_part_from_html_response(response: Response) -> String {
+fn get_main_part_from_html_response(body: String) -> String {
     let pattern = regex::RegexBuilder::new(r"<main>[\s\S]*</main>")
         .multi_line(true)
         .build()
-        .unwrap();
-    let body = response.text().unwrap();
-    let m = pattern.find(body.as_str()).unwrap();
-    m.as_str().to_string()
+        .expect("Invalid regex for main part of response");
+    // Responses that are not a full page, e.g. when not logged in, are used as is.
+    match pattern.find(body.as_str()) {
+        Some(m) => m.as_str().to_string(),
+        None => body,
+    }
+}
+
+/// Helpers shared by the tests of the modules.
+#[cfg(test)]
+pub(crate) mod test_utils {
+    use tempfile::TempDir;
+
+    /// Empty data directory, which is removed when it is dropped.
+    pub fn temp_data_dir() -> TempDir {
+        tempfile::Builder::new()
+            .prefix("aoc-")
+            .tempdir()
+            .expect("Failed to create temporary data directory")
+    }
 }
 
 #[cfg(test)]
 mod test {
     use fake::{Fake, Faker};
     use wiremock::{
-        matchers::{method, path},
+        matchers::{body_string_contains, method, path},
         Mock, MockServer, ResponseTemplate,
     };
 
     use super::*;
-    use crate::Year;
+    use crate::{test_utils::temp_data_dir, Year};
 
     #[test]
     fn get_token_test() {
         let value = "abc";
         env::set_var(TOKEN_NAME, value);
 
-        assert_eq!(value, get_token());
+        assert_eq!(value, get_token().unwrap());
+    }
+
+    #[test]
+    fn format_hms_test() {
+        assert_eq!(format_hms(&Duration::from_secs(0)), "00:00:00");
+        assert_eq!(format_hms(&Duration::from_millis(61_500)), "00:01:02");
+        assert_eq!(format_hms(&Duration::from_secs(26 * 3600 + 5)), "26:00:05");
     }
 
     #[test]
@@ -378,6 +879,152 @@ mod test {
         assert!(response.is_err());
     }
 
+    #[async_std::test]
+    async fn download_input_before_unlocked() {
+        // Arrange
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2017/day/1/input"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let client = AocClient::new(Url::parse(&mock_server.uri()).unwrap(), Faker.fake());
+
+        // Act
+        let response = client.download_input((Year::Y2017, 1).into());
+
+        // Assert
+        assert!(matches!(
+            response,
+            Err(Error::PuzzleNotUnlocked { problem, .. }) if problem == (Year::Y2017, 1).into()
+        ));
+    }
+
+    #[async_std::test]
+    async fn download_input_when_not_logged_in() {
+        // Arrange
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2017/day/1/input"))
+            .respond_with(ResponseTemplate::new(400))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let client = AocClient::new(Url::parse(&mock_server.uri()).unwrap(), Faker.fake());
+
+        // Act
+        let response = client.download_input((Year::Y2017, 1).into());
+
+        // Assert
+        assert!(matches!(response, Err(Error::Unauthorized)));
+    }
+
+    #[async_std::test]
+    async fn download_input_retries_on_server_error() {
+        // Arrange
+        let body: String = Faker.fake();
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2017/day/1/input"))
+            .respond_with(ResponseTemplate::new(500))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/2017/day/1/input"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(body.clone()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let client = AocClient::builder()
+            .base_url(Url::parse(&mock_server.uri()).unwrap())
+            .token(Faker.fake::<String>())
+            .retry_policy(RetryPolicy::default().initial_backoff(Duration::ZERO))
+            .build()
+            .unwrap();
+
+        // Act
+        let input = client.download_input((Year::Y2017, 1).into()).unwrap();
+
+        // Assert
+        assert_eq!(body, input);
+    }
+
+    #[async_std::test]
+    async fn get_input_does_not_cache_invalid_input() {
+        // Arrange
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2017/day/1/input"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("<!DOCTYPE html>"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let data_dir = temp_data_dir();
+        let client = AocClient::new(Url::parse(&mock_server.uri()).unwrap(), Faker.fake())
+            .with_data_dir(data_dir.path());
+
+        // Act
+        let input = client.get_input((Year::Y2017, 1).into());
+
+        // Assert
+        assert!(matches!(input, Err(Error::InvalidInput { .. })));
+        assert!(
+            !cache::get_input_cache_full_filename(data_dir.path(), (Year::Y2017, 1).into())
+                .exists()
+        );
+    }
+
+    #[async_std::test]
+    async fn get_input_offline() {
+        // Arrange
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+        let data_dir = temp_data_dir();
+        let client = AocClient::builder()
+            .base_url(Url::parse(&mock_server.uri()).unwrap())
+            .data_dir(data_dir.path())
+            .offline(true)
+            .build()
+            .unwrap();
+
+        // Act
+        let input = client.get_input((Year::Y2017, 1).into());
+
+        // Assert
+        assert!(matches!(input, Err(Error::InputNotCached(_))));
+    }
+
+    #[async_std::test]
+    async fn download_input_without_retries() {
+        // Arrange
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2017/day/1/input"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let client = AocClient::builder()
+            .base_url(Url::parse(&mock_server.uri()).unwrap())
+            .token(Faker.fake::<String>())
+            .retry_policy(RetryPolicy::none())
+            .build()
+            .unwrap();
+
+        // Act
+        let response = client.download_input((Year::Y2017, 1).into());
+
+        // Assert
+        assert!(response.is_err());
+    }
+
     #[async_std::test]
     async fn submit_answer() {
         // Arrange
@@ -399,6 +1046,64 @@ mod test {
         assert!(response.is_ok());
     }
 
+    #[async_std::test]
+    async fn submit_part_two_of_last_day() {
+        // Arrange
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/2017/day/25/answer"))
+            .and(body_string_contains("level=2"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string(include_str!("../data/finished.html")),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let data_dir = temp_data_dir();
+        let mut scores = ScoreMap::load(data_dir.path(), Year::Y2017).unwrap();
+        scores.merge((1..=24).map(|day| (day, Level::B))).unwrap();
+        scores.set_score_for_day(25, &Level::A).unwrap();
+        let client = AocClient::new(Url::parse(&mock_server.uri()).unwrap(), Faker.fake())
+            .with_data_dir(data_dir.path());
+
+        // Act
+        let result = client.submit((Year::Y2017, 25).into(), Level::B, &Faker.fake());
+        let again = client.submit((Year::Y2017, 25).into(), Level::B, &Faker.fake());
+
+        // Assert
+        assert_eq!(result.unwrap(), SubmissionResult::Finished);
+        assert_eq!(again.unwrap(), SubmissionResult::SkippingAlreadyCompleted);
+    }
+
+    #[async_std::test]
+    async fn refuse_to_finish_with_missing_stars() {
+        // Arrange
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+        let data_dir = temp_data_dir();
+        let mut scores = ScoreMap::load(data_dir.path(), Year::Y2017).unwrap();
+        scores.merge((1..=23).map(|day| (day, Level::B))).unwrap();
+        scores.set_score_for_day(24, &Level::A).unwrap();
+        let client = AocClient::new(Url::parse(&mock_server.uri()).unwrap(), Faker.fake())
+            .with_data_dir(data_dir.path());
+
+        // Act
+        let result = client.finish(Year::Y2017);
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(Error::MissingStars {
+                year: Year::Y2017,
+                missing: 2
+            })
+        ));
+    }
+
     #[test]
     fn parse_to_recent_response() {
         // Arrange
@@ -413,4 +1118,153 @@ mod test {
             SubmissionResult::TooRecent(Duration::from_secs(4 * 60 + 36))
         );
     }
+
+    #[async_std::test]
+    async fn get_private_leaderboard() {
+        // Arrange
+        let body = include_str!("../data/leaderboard.json");
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2023/leaderboard/private/view/1.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let client = AocClient::new(Url::parse(&mock_server.uri()).unwrap(), Faker.fake());
+
+        // Act
+        let leaderboard = client.private_leaderboard(Year::Y2023, 1).unwrap();
+
+        // Assert
+        assert_eq!(leaderboard.owner_id, 1);
+        assert_eq!(leaderboard.members.len(), 3);
+    }
+
+    #[async_std::test]
+    async fn get_private_leaderboard_without_access() {
+        // Arrange
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2023/leaderboard/private/view/1.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("<!DOCTYPE html>"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let client = AocClient::new(Url::parse(&mock_server.uri()).unwrap(), Faker.fake());
+
+        // Act
+        let result = client.private_leaderboard(Year::Y2023, 1);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[async_std::test]
+    async fn whoami() {
+        // Arrange
+        let body = include_str!("../data/settings.html");
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/settings"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let client = AocClient::new(Url::parse(&mock_server.uri()).unwrap(), Faker.fake());
+
+        // Act
+        let name = client.whoami().unwrap();
+
+        // Assert
+        assert_eq!(name, "OliverFlecke");
+    }
+
+    #[async_std::test]
+    async fn whoami_with_expired_token() {
+        // Arrange
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/settings"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("<!DOCTYPE html>"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let client = AocClient::new(Url::parse(&mock_server.uri()).unwrap(), Faker.fake());
+
+        // Act
+        let result = client.whoami();
+
+        // Assert
+        assert!(matches!(result, Err(Error::Unauthorized)));
+    }
+
+    #[test]
+    fn parse_correct_response() {
+        let body = include_str!("../data/correct.html");
+
+        assert_eq!(
+            response_body_to_submission_result(body).unwrap(),
+            SubmissionResult::Correct
+        );
+    }
+
+    #[test]
+    fn parse_incorrect_response() {
+        let body = include_str!("../data/incorrect.html");
+
+        assert_eq!(
+            response_body_to_submission_result(body).unwrap(),
+            SubmissionResult::Incorrect(Duration::from_secs(60))
+        );
+    }
+
+    #[test]
+    fn parse_too_high_response() {
+        let body = include_str!("../data/too_high.html");
+
+        assert_eq!(
+            response_body_to_submission_result(body).unwrap(),
+            SubmissionResult::TooHigh(Duration::from_secs(5 * 60))
+        );
+    }
+
+    #[test]
+    fn parse_too_low_response() {
+        let body = include_str!("../data/too_low.html");
+
+        assert_eq!(
+            response_body_to_submission_result(body).unwrap(),
+            SubmissionResult::TooLow(Duration::from_secs(60))
+        );
+    }
+
+    #[test]
+    fn parse_finished_response() {
+        let body = include_str!("../data/finished.html");
+
+        assert_eq!(
+            response_body_to_submission_result(body).unwrap(),
+            SubmissionResult::Finished
+        );
+    }
+
+    #[test]
+    fn parse_wrong_level_response() {
+        let body = include_str!("../data/wrong_level.html");
+
+        assert_eq!(
+            response_body_to_submission_result(body).unwrap(),
+            SubmissionResult::WrongLevel
+        );
+    }
+
+    #[test]
+    fn parse_not_logged_in_response() {
+        let body = include_str!("../data/not_logged_in.html");
+
+        assert!(matches!(
+            response_body_to_submission_result(body),
+            Err(Error::Unauthorized)
+        ));
+    }
 }
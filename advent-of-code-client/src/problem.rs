@@ -1,4 +1,8 @@
-use std::fmt::Display;
+use std::{
+    fmt::{Debug, Display},
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 use derive_getters::{Dissolve, Getters};
 
@@ -18,7 +22,7 @@ use derive_getters::{Dissolve, Getters};
 /// assert_eq!(a, b);
 /// assert_eq!(b, c);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Getters, Dissolve)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Getters, Dissolve)]
 pub struct Problem {
     year: Year,
     day: Day,
@@ -29,6 +33,45 @@ impl Problem {
     pub const fn new(year: Year, day: Day) -> Self {
         Self { year, day }
     }
+
+    /// Time the puzzle is unlocked, which is at midnight US-Eastern time
+    /// (UTC-5) on its day of December.
+    pub fn unlock_time(&self) -> SystemTime {
+        const UNLOCK_HOUR_UTC: u64 = 5;
+        let days = days_since_epoch(self.year.as_int().into(), 12, self.day.into());
+
+        UNIX_EPOCH + Duration::from_secs((days * 24 + UNLOCK_HOUR_UTC) * 60 * 60)
+    }
+
+    /// Whether the puzzle has a second part. Day 25 only has one, as its
+    /// second star is given for collecting all other stars of the year.
+    pub fn has_part_two(&self) -> bool {
+        self.day != 25
+    }
+
+    /// Time left until the puzzle is unlocked, or `None` if it already is.
+    pub fn time_until_unlock(&self) -> Option<Duration> {
+        self.unlock_time()
+            .duration_since(SystemTime::now())
+            .ok()
+            .filter(|remaining| !remaining.is_zero())
+    }
+}
+
+/// Number of days from the Unix epoch to a date after it.
+/// See http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_since_epoch(year: u64, month: u64, day: u64) -> u64 {
+    let (year, month) = if month <= 2 {
+        (year - 1, month + 9)
+    } else {
+        (year, month - 3)
+    };
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146097 + day_of_era - 719468
 }
 
 impl From<(Year, Day)> for Problem {
@@ -41,11 +84,8 @@ impl TryFrom<(u16, u8)> for Problem {
     type Error = String;
 
     fn try_from((year, day): (u16, u8)) -> Result<Self, Self::Error> {
-        let Some(year) = Year::from_repr(year) else {
-            return Err(format!(
-                "Invalid year provided. Valid years are from 2015 to {}",
-                Year::max()
-            ));
+        let Some(year) = Year::new(year) else {
+            return Err(invalid_year_message());
         };
         if day == 0 || day > 25 {
             return Err("Invalid day provided. Valid days are from 1 to 25".to_string());
@@ -61,35 +101,119 @@ impl Display for Problem {
     }
 }
 
-/// Represents a year of advent of code challenges.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, clap::ValueEnum, strum::FromRepr)]
-#[cfg_attr(test, derive(fake::Dummy))]
-#[repr(u16)]
-pub enum Year {
-    Y2016 = 2016,
-    Y2017 = 2017,
-    Y2018 = 2018,
-    Y2019 = 2019,
-    Y2020 = 2020,
-    Y2021 = 2021,
-    Y2022 = 2022,
-    Y2023 = 2023,
-    Y2024 = 2024,
-}
+/// Represents a year of advent of code challenges, from the first event in
+/// 2015 to the latest event that has started, see [Year::max].
+///
+/// Years are parsed from either `2023` or `y2023`, and displayed as `Y2023`.
+///
+/// ```rust
+/// # use advent_of_code_client::Year;
+/// assert_eq!("2023".parse(), Ok(Year::Y2023));
+/// assert_eq!("y2023".parse(), Ok(Year::Y2023));
+/// assert_eq!(Year::Y2023.to_string(), "Y2023");
+/// assert_eq!(Year::new(2014), None);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
 
 impl Year {
+    pub const Y2015: Year = Year(2015);
+    pub const Y2016: Year = Year(2016);
+    pub const Y2017: Year = Year(2017);
+    pub const Y2018: Year = Year(2018);
+    pub const Y2019: Year = Year(2019);
+    pub const Y2020: Year = Year(2020);
+    pub const Y2021: Year = Year(2021);
+    pub const Y2022: Year = Year(2022);
+    pub const Y2023: Year = Year(2023);
+    pub const Y2024: Year = Year(2024);
+    pub const Y2025: Year = Year(2025);
+
+    /// Year of the first event.
+    pub const MIN: Year = Self::Y2015;
+
+    /// Create a year, if it is from [Year::MIN] to [Year::max].
+    pub fn new(year: u16) -> Option<Year> {
+        (Self::MIN.0..=Self::max().0)
+            .contains(&year)
+            .then_some(Year(year))
+    }
+
+    /// Create a year in a const context, e.g. for a year without a named
+    /// constant. Unlike [Year::new], only years before [Year::MIN] are
+    /// rejected, which fails to compile when evaluated at compile time.
+    ///
+    /// ```rust
+    /// # use advent_of_code_client::Year;
+    /// const YEAR: Year = Year::from_const(2023);
+    /// assert_eq!(YEAR, Year::Y2023);
+    /// ```
+    pub const fn from_const(year: u16) -> Year {
+        assert!(year >= Self::MIN.0, "Advent of Code started in 2015");
+        Year(year)
+    }
+
     pub const fn as_int(self) -> u16 {
-        self as u16
+        self.0
+    }
+
+    /// Latest year with an event, which starts when the first puzzle is
+    /// unlocked in December.
+    pub fn max() -> Year {
+        let mut year = Self::MIN;
+        while Problem::new(Year(year.0 + 1), 1)
+            .time_until_unlock()
+            .is_none()
+        {
+            year.0 += 1;
+        }
+
+        year
     }
 
-    pub const fn max() -> Year {
-        Self::Y2024
+    /// All years from [Year::MIN] to [Year::max].
+    pub fn all() -> impl Iterator<Item = Year> {
+        (Self::MIN.0..=Self::max().0).map(Year)
+    }
+}
+
+fn invalid_year_message() -> String {
+    format!(
+        "Invalid year provided. Valid years are from {} to {}",
+        Year::MIN.as_int(),
+        Year::max().as_int()
+    )
+}
+
+impl FromStr for Year {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.strip_prefix(['y', 'Y'])
+            .unwrap_or(s)
+            .parse()
+            .ok()
+            .and_then(Year::new)
+            .ok_or_else(invalid_year_message)
     }
 }
 
 impl Display for Year {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        write!(f, "Y{}", self.0)
+    }
+}
+
+impl Debug for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+impl fake::Dummy<fake::Faker> for Year {
+    fn dummy_with_rng<R: fake::Rng + ?Sized>(_: &fake::Faker, rng: &mut R) -> Self {
+        Year(rng.gen_range(Self::MIN.0..=Self::max().0))
     }
 }
 
@@ -133,4 +257,64 @@ mod test {
         let day: Day = (1..=25).fake();
         assert_eq!(Into::<Problem>::into((year, day)), Problem { year, day });
     }
+
+    #[test]
+    fn unlock_time_is_midnight_eastern() {
+        // 2023-12-01T05:00:00Z and 2020-12-25T05:00:00Z.
+        assert_eq!(
+            Problem::new(Year::Y2023, 1).unlock_time(),
+            UNIX_EPOCH + Duration::from_secs(1701406800)
+        );
+        assert_eq!(
+            Problem::new(Year::Y2020, 25).unlock_time(),
+            UNIX_EPOCH + Duration::from_secs(1608872400)
+        );
+        assert_eq!(Problem::new(Year::Y2016, 1).time_until_unlock(), None);
+    }
+
+    #[test]
+    fn last_day_has_no_part_two() {
+        assert!(Problem::new(Year::Y2023, 24).has_part_two());
+        assert!(!Problem::new(Year::Y2023, 25).has_part_two());
+    }
+
+    #[test]
+    fn parse_year() {
+        assert_eq!("2015".parse(), Ok(Year::Y2015));
+        assert_eq!("y2023".parse(), Ok(Year::Y2023));
+        assert_eq!("Y2023".parse(), Ok(Year::Y2023));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("y20x3".parse::<Year>().is_err());
+        assert!((Year::max().as_int() + 1)
+            .to_string()
+            .parse::<Year>()
+            .is_err());
+    }
+
+    #[test]
+    fn format_year() {
+        assert_eq!(Year::Y2023.to_string(), "Y2023");
+        assert_eq!(
+            format!("{:?}", Problem::new(Year::Y2023, 1)),
+            "Problem { year: Y2023, day: 1 }"
+        );
+    }
+
+    #[test]
+    fn max_year() {
+        assert!(Year::max() >= Year::Y2025);
+        assert!(Problem::new(Year::max(), 1).time_until_unlock().is_none());
+        assert_eq!(Year::new(Year::max().as_int() + 1), None);
+        assert_eq!(Year::all().next(), Some(Year::MIN));
+        assert_eq!(Year::all().last(), Some(Year::max()));
+    }
+
+    #[test]
+    fn problem_from_2015() {
+        assert_eq!(
+            (2015_u16, 1_u8).try_into(),
+            Ok(Problem::new(Year::Y2015, 1))
+        );
+        assert!(Problem::try_from((2014_u16, 1_u8)).is_err());
+    }
 }
@@ -0,0 +1,146 @@
+//! Named profiles with session tokens, to switch between several accounts
+//! on Advent of Code. Each profile keeps its data separate, so inputs and
+//! stars for different accounts are never mixed.
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::{Error, Result};
+
+/// Environment variable to select a profile.
+pub(crate) const PROFILE_NAME: &str = "AOC_PROFILE";
+
+/// Environment variable to set the location of the config file.
+const CONFIG_NAME: &str = "AOC_CONFIG";
+
+/// Directory in the data directory with the data of each profile.
+const PROFILES_DIRECTORY: &str = ".profiles";
+
+/// Config file with the session token for each profile, e.g.
+///
+/// ```toml
+/// [profiles.main]
+/// token = "<session token>"
+///
+/// [profiles.alt]
+/// token = "<session token for another account>"
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+pub struct Config {
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Profile {
+    pub token: String,
+}
+
+impl Config {
+    /// Load the config from [config_path]. A missing file is loaded as empty.
+    pub fn load() -> Result<Config> {
+        Self::load_from(&config_path())
+    }
+
+    /// Load the config from a file. A missing file is loaded as empty.
+    pub fn load_from(path: &Path) -> Result<Config> {
+        match fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content).map_err(|source| Error::InvalidConfig {
+                path: path.to_path_buf(),
+                source,
+            }),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Get the token for a profile.
+    pub fn token(&self, profile: &str) -> Result<&str> {
+        self.profiles
+            .get(profile)
+            .map(|profile| profile.token.as_str())
+            .ok_or_else(|| Error::UnknownProfile(profile.to_string()))
+    }
+}
+
+/// Location of the config file, which is the first of:
+///
+/// - The file set in the `AOC_CONFIG` environment variable.
+/// - `advent-of-code/config.toml` in the XDG config directory, i.e.
+///   `$XDG_CONFIG_HOME` or `~/.config`.
+pub fn config_path() -> PathBuf {
+    if let Some(path) = env::var_os(CONFIG_NAME).filter(|path| !path.is_empty()) {
+        return PathBuf::from(path);
+    }
+
+    env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+        .unwrap_or_default()
+        .join("advent-of-code")
+        .join("config.toml")
+}
+
+/// Directory with the data for a profile inside `data_dir`. Fails with
+/// [Error::InvalidProfileName] if the name would point outside of it.
+pub fn profile_data_dir(data_dir: &Path, profile: &str) -> Result<PathBuf> {
+    let is_valid = !profile.is_empty()
+        && profile != "."
+        && !profile.contains("..")
+        && !profile.contains(['/', '\\']);
+    if !is_valid {
+        return Err(Error::InvalidProfileName(profile.to_string()));
+    }
+
+    Ok(data_dir.join(PROFILES_DIRECTORY).join(profile))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_config() {
+        let config: Config = toml::from_str(
+            r#"
+            [profiles.main]
+            token = "abc"
+
+            [profiles.alt]
+            token = "def"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.token("main").unwrap(), "abc");
+        assert_eq!(config.token("alt").unwrap(), "def");
+        assert!(matches!(
+            config.token("other"),
+            Err(Error::UnknownProfile(name)) if name == "other"
+        ));
+    }
+
+    #[test]
+    fn reject_profile_names_outside_data_dir() {
+        let data_dir = Path::new("data");
+
+        assert_eq!(
+            profile_data_dir(data_dir, "alt").unwrap(),
+            data_dir.join(".profiles").join("alt")
+        );
+        for name in ["", ".", "..", "../other", "a/b", "a\\b", "/abs"] {
+            assert!(
+                matches!(
+                    profile_data_dir(data_dir, name),
+                    Err(Error::InvalidProfileName(n)) if n == name
+                ),
+                "{name}"
+            );
+        }
+    }
+}
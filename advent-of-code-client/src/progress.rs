@@ -0,0 +1,191 @@
+use std::path::Path;
+
+use colored::Colorize;
+
+use crate::{cache, score::ScoreMap, Day, Level, Problem, Result, Year};
+
+/// Local progress for a single day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayProgress {
+    pub day: Day,
+    /// Highest level solved, if any.
+    pub solved: Option<Level>,
+    /// Whether the input is cached locally.
+    pub input_cached: bool,
+}
+
+impl DayProgress {
+    pub fn stars(&self) -> usize {
+        self.solved.map_or(0, |level| level.as_int() as usize)
+    }
+}
+
+/// Local progress for all days of a year, based on the tracked stars and
+/// the cached inputs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct YearProgress {
+    pub year: Year,
+    pub days: Vec<DayProgress>,
+}
+
+impl YearProgress {
+    /// Load the progress for a year from the data in `data_dir`.
+    pub fn load(data_dir: &Path, year: Year) -> Result<Self> {
+        let scores = ScoreMap::load(data_dir, year)?;
+        let mut days: Vec<DayProgress> = (1..=25)
+            .map(|day| DayProgress {
+                day,
+                solved: scores.get_score_for_day(day),
+                input_cached: cache::get_input_cache_full_filename(
+                    data_dir,
+                    Problem::new(year, day),
+                )
+                .exists(),
+            })
+            .collect();
+
+        // Day 25 only has one part, and its second star is given once all
+        // other stars of the year are collected.
+        if let Some((last, others)) = days.split_last_mut() {
+            if last.solved == Some(Level::A)
+                && others.iter().all(|day| day.solved == Some(Level::B))
+            {
+                last.solved = Some(Level::B);
+            }
+        }
+
+        Ok(Self { year, days })
+    }
+
+    /// Progress for all years from [Year::MIN] to [Year::max].
+    pub fn load_all(data_dir: &Path) -> Result<Vec<Self>> {
+        Year::all().map(|year| Self::load(data_dir, year)).collect()
+    }
+
+    pub fn stars(&self) -> usize {
+        self.days.iter().map(DayProgress::stars).sum()
+    }
+}
+
+/// Draw a calendar grid with a row for each year and a column for each day.
+///
+/// Each cell shows the stars for the day, whether the input is cached, and,
+/// if `has_solver` is given, whether a solution exists for the problem.
+pub fn render_dashboard(
+    progress: &[YearProgress],
+    has_solver: Option<&dyn Fn(Problem) -> bool>,
+) -> String {
+    let mut output = String::new();
+
+    let days: String = (1..=25).map(|day| format!("{day:<4}")).collect();
+    output.push_str(&format!("{}\n", format!("Year | {days}| Stars").cyan()));
+
+    for year in progress {
+        let cells: String = year
+            .days
+            .iter()
+            .map(|day| {
+                let stars = match day.solved {
+                    Some(Level::B) => "*".yellow(),
+                    Some(Level::A) => "*".blue(),
+                    None => ".".dimmed(),
+                };
+                let solver = match has_solver {
+                    Some(has_solver) if has_solver(Problem::new(year.year, day.day)) => "s".green(),
+                    _ => " ".normal(),
+                };
+                let input = if day.input_cached {
+                    "i".cyan()
+                } else {
+                    " ".normal()
+                };
+                format!("{stars}{solver}{input} ")
+            })
+            .collect();
+
+        output.push_str(&format!(
+            "{} | {cells}| {:>5}\n",
+            year.year.as_int(),
+            year.stars().to_string().green()
+        ));
+    }
+
+    output.push_str(&format!(
+        "\n{} two stars  {} one star  {} no stars",
+        "*".yellow(),
+        "*".blue(),
+        ".".dimmed()
+    ));
+    if has_solver.is_some() {
+        output.push_str(&format!("  {} solution exists", "s".green()));
+    }
+    output.push_str(&format!("  {} input cached\n", "i".cyan()));
+
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utils::temp_data_dir;
+
+    fn year_progress() -> YearProgress {
+        let mut days: Vec<DayProgress> = (1..=25)
+            .map(|day| DayProgress {
+                day,
+                solved: None,
+                input_cached: false,
+            })
+            .collect();
+        days[0].solved = Some(Level::B);
+        days[0].input_cached = true;
+        days[1].solved = Some(Level::A);
+
+        YearProgress {
+            year: Year::Y2023,
+            days,
+        }
+    }
+
+    #[test]
+    fn count_stars() {
+        assert_eq!(year_progress().stars(), 3);
+    }
+
+    #[test]
+    fn count_second_star_of_last_day() {
+        let data_dir = temp_data_dir();
+        let mut scores = ScoreMap::load(data_dir.path(), Year::Y2017).unwrap();
+        scores
+            .merge((1..=24).map(|day| (day, Level::B)).chain([(25, Level::A)]))
+            .unwrap();
+        assert_eq!(
+            YearProgress::load(data_dir.path(), Year::Y2017)
+                .unwrap()
+                .stars(),
+            50
+        );
+
+        scores.set_score_for_day(24, &Level::A).unwrap();
+        assert_eq!(
+            YearProgress::load(data_dir.path(), Year::Y2017)
+                .unwrap()
+                .stars(),
+            48
+        );
+    }
+
+    #[test]
+    fn render_dashboard_test() {
+        colored::control::set_override(false);
+        let has_solver = |problem: Problem| *problem.day() == 3;
+
+        let dashboard = render_dashboard(&[year_progress()], Some(&has_solver));
+        let lines: Vec<&str> = dashboard.lines().collect();
+
+        assert!(lines[0].starts_with("Year | 1   2   3   "));
+        assert!(lines[1].starts_with("2023 | * i *   .s  .   "));
+        assert!(lines[1].ends_with("|     3"));
+        assert!(lines[3].contains("solution exists"));
+    }
+}
@@ -4,34 +4,50 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use crate::{Day, Level, Year};
+use crate::{Day, Error, Level, Result, Year};
 
 const STARS_DIRECTORY: &str = ".stars";
 
 pub(crate) struct ScoreMap {
+    directory: PathBuf,
     year: Year,
     map: HashMap<String, u8>,
 }
 
 impl ScoreMap {
-    pub fn load(year: Year) -> ScoreMap {
-        let filename = Self::get_filename(year);
+    /// Load the stars for a year from the `.stars` directory in `data_dir`.
+    pub fn load(data_dir: &Path, year: Year) -> Result<ScoreMap> {
+        let directory = data_dir.join(STARS_DIRECTORY);
+        let filename = Self::get_filename(&directory, year);
         let content = match fs::read_to_string(filename.as_path()) {
             Ok(value) => value,
-            Err(err) => match err.kind() {
-                std::io::ErrorKind::NotFound => {
-                    return ScoreMap {
-                        year,
-                        map: HashMap::new(),
-                    }
-                }
-                _ => panic!("{}", err),
-            },
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(ScoreMap {
+                    directory,
+                    year,
+                    map: HashMap::new(),
+                })
+            }
+            Err(err) => return Err(err.into()),
         };
-        match serde_json::from_str(&content) {
-            Ok(map) => ScoreMap { year, map },
-            Err(_) => panic!("Unable to parse stars"),
+
+        let map: HashMap<String, u8> =
+            serde_json::from_str(&content).map_err(|source| Error::CorruptStateFile {
+                path: filename.clone(),
+                source,
+            })?;
+        if let Some((day, level)) = map.iter().find(|(_, level)| !(1..=2).contains(*level)) {
+            return Err(Error::CorruptStateFile {
+                path: filename,
+                source: serde::de::Error::custom(format!("invalid level {level} for {day}")),
+            });
         }
+
+        Ok(ScoreMap {
+            directory,
+            year,
+            map,
+        })
     }
 
     pub fn get_score_for_day(&self, day: Day) -> Option<Level> {
@@ -40,32 +56,113 @@ impl ScoreMap {
             .map(|x| Level::from(*x))
     }
 
-    pub fn set_score_for_day(&mut self, day: Day, score: &Level) {
+    pub fn set_score_for_day(&mut self, day: Day, score: &Level) -> Result<()> {
         self.map.insert(Self::day_to_key(day), score.as_int());
-        self.store();
+        self.store()
+    }
+
+    /// Merge stars into the scores, keeping the highest level for each day.
+    /// Returns the number of days that were updated.
+    pub fn merge(&mut self, stars: impl IntoIterator<Item = (Day, Level)>) -> Result<usize> {
+        let mut updated = 0;
+        for (day, level) in stars {
+            if self
+                .get_score_for_day(day)
+                .is_none_or(|current| current < level)
+            {
+                self.map.insert(Self::day_to_key(day), level.as_int());
+                updated += 1;
+            }
+        }
+
+        if updated > 0 {
+            self.store()?;
+        }
+        Ok(updated)
     }
 
     fn day_to_key(day: Day) -> String {
         format!("day{}", day)
     }
 
-    fn get_filename(year: Year) -> PathBuf {
-        Path::new(STARS_DIRECTORY).join(format!("{}.json", year.as_int()))
+    fn get_filename(directory: &Path, year: Year) -> PathBuf {
+        directory.join(format!("{}.json", year.as_int()))
     }
 
-    fn store(&self) {
-        fs::create_dir_all(STARS_DIRECTORY).unwrap();
-        let filename = Self::get_filename(self.year);
-        match serde_json::to_string(&self.map) {
-            Ok(content) => fs::write(filename, content).unwrap(),
-            Err(err) => panic!("Unable to serialize scores: {}", err),
-        };
+    fn store(&self) -> Result<()> {
+        fs::create_dir_all(&self.directory)?;
+        let filename = Self::get_filename(&self.directory, self.year);
+        let content =
+            serde_json::to_string(&self.map).map_err(|source| Error::CorruptStateFile {
+                path: filename.clone(),
+                source,
+            })?;
+        fs::write(filename, content)?;
+        Ok(())
     }
 }
 
+/// Parse the stars for each day from the calendar page of a year. Days
+/// without any stars are left out.
+pub(crate) fn stars_from_calendar(html: &str) -> Vec<(Day, Level)> {
+    let re = regex::Regex::new(r#"class="calendar-day(?<day>\d+)(?<state>[^"]*)""#)
+        .expect("Invalid regex for calendar");
+
+    re.captures_iter(html)
+        .filter_map(|caps| {
+            let day = caps["day"].parse().ok()?;
+            let state = &caps["state"];
+            if state.contains("calendar-verycomplete") {
+                Some((day, Level::B))
+            } else if state.contains("calendar-complete") {
+                Some((day, Level::A))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::test_utils::temp_data_dir;
+
+    #[test]
+    fn parse_calendar() {
+        let html = include_str!("../data/calendar.html");
+
+        assert_eq!(
+            stars_from_calendar(html),
+            vec![(1, Level::B), (2, Level::B), (3, Level::A)]
+        );
+    }
+
+    #[test]
+    fn merge_keeps_highest_level() {
+        let mut scores = ScoreMap {
+            directory: PathBuf::new(),
+            year: Year::Y2023,
+            map: HashMap::from([("day1".to_string(), 2), ("day2".to_string(), 1)]),
+        };
+        let stars = [(1, Level::A), (2, Level::A)];
+
+        // Nothing is stored, as no days are updated.
+        assert_eq!(scores.merge(stars).unwrap(), 0);
+        assert_eq!(scores.get_score_for_day(1), Some(Level::B));
+    }
+
+    #[test]
+    fn load_corrupt_scores() {
+        let data_dir = temp_data_dir();
+        let data_dir = data_dir.path();
+        fs::create_dir_all(data_dir.join(STARS_DIRECTORY)).unwrap();
+        fs::write(data_dir.join(".stars/2023.json"), r#"{"day1":3}"#).unwrap();
+
+        let result = ScoreMap::load(data_dir, Year::Y2023);
+
+        assert!(matches!(result, Err(Error::CorruptStateFile { .. })));
+    }
 
     #[test]
     fn serialize_empty_test() {
@@ -0,0 +1,41 @@
+//! Tracking of when the next answer can be submitted, so requests that are
+//! known to be rejected as too recent are not sent.
+use std::{
+    fs,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// File in the data directory storing the earliest time, in seconds since
+/// the Unix epoch, that another answer can be submitted.
+const NEXT_SUBMISSION_FILE: &str = ".stars/next_submission";
+
+/// Time left before another answer can be submitted, if any.
+pub fn remaining(data_dir: &Path) -> Option<Duration> {
+    let not_before = fs::read_to_string(data_dir.join(NEXT_SUBMISSION_FILE))
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+
+    (UNIX_EPOCH + Duration::from_secs(not_before))
+        .duration_since(SystemTime::now())
+        .ok()
+        .filter(|remaining| !remaining.is_zero())
+}
+
+/// Record that no answers can be submitted for `wait` from now.
+pub fn record(data_dir: &Path, wait: Duration) -> std::io::Result<()> {
+    let not_before = SystemTime::now() + wait;
+    let seconds = not_before
+        .duration_since(UNIX_EPOCH)
+        .expect("Time should be after the Unix epoch")
+        .as_secs_f64()
+        .ceil() as u64;
+
+    let path = data_dir.join(NEXT_SUBMISSION_FILE);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, seconds.to_string())
+}
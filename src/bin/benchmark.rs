@@ -1,14 +1,20 @@
 use std::{
+    collections::HashMap,
     error::Error,
     time::{Duration, Instant},
 };
 
-use advent_of_code::solutions::{answer::Answer, get_solver};
-use advent_of_code_client::{AocClient, Year};
+use advent_of_code::solutions::{
+    answer::Answer,
+    error::{SolutionError, SolutionResult},
+    get_solver, implemented_days, implemented_problems,
+};
+use advent_of_code_client::{input_provider, AnswerMap, AocClient, Level, Problem, Year};
 #[cfg(feature = "memory-profile")]
 use byte_unit::Byte;
 use clap::Parser;
 use colored::Colorize;
+use itertools::Itertools;
 use serde::Serialize;
 
 #[cfg(all(feature = "memory-profile", feature = "time-profile"))]
@@ -16,9 +22,12 @@ compile_error!("feature 'memory-profile' and 'time-profile' cannot be enabled at
 
 #[derive(Debug, Parser)]
 struct Args {
-    #[arg(value_enum)]
-    year: Year,
-    #[arg(help = "Single day to run the benchmark for")]
+    #[arg(
+        required_unless_present = "verify",
+        help = "Year to run the benchmark for. With --verify, all years are run if not given"
+    )]
+    year: Option<Year>,
+    #[arg(requires = "year", help = "Single day to run the benchmark for")]
     day: Option<u8>,
     #[arg(
         short = 'i',
@@ -29,13 +38,28 @@ struct Args {
     iterations: u32,
     #[arg(short, long, help = "Output csv file to write the benchmarks to")]
     output: Option<String>,
+    #[arg(
+        long,
+        help = "Verify answers against the known correct answers, and fail if any has changed"
+    )]
+    verify: bool,
+    /// Profile in the config file of `aoc` to use the token of. Each profile
+    /// has its own inputs and answers.
+    ///
+    /// If not provided it will default to `AOC_PROFILE`.
+    #[arg(short = 'p', long)]
+    profile: Option<String>,
+    /// Never send requests to Advent of Code, and only use cached inputs.
+    ///
+    /// Can also be enabled by setting `AOC_OFFLINE`.
+    #[arg(long)]
+    offline: bool,
 }
 
 const ANSWER_WIDTH: usize = 32;
 
-/// Benchmark a year. This will run and time all available solutions for the given year.
-/// It assumes that the solutions are created from the start and to the end, and will break
-/// if on the first day that is missing.
+/// Benchmark a year. This will run and time all registered solutions for the given year,
+/// or for all years when verifying the answers without a year.
 ///
 /// To run with memory profiling enabled, use:
 /// ```sh
@@ -46,8 +70,10 @@ const ANSWER_WIDTH: usize = 32;
 fn main() -> Result<(), Box<dyn Error>> {
     let args = Args::parse();
 
-    let year = args.year;
-    println!("Running benchmarks for {year:?}");
+    match args.year {
+        Some(year) => println!("Running benchmarks for {year:?}"),
+        None => println!("Running benchmarks for all years"),
+    }
 
     // Write header
     print!(
@@ -63,7 +89,11 @@ fn main() -> Result<(), Box<dyn Error>> {
     {
         print!(
             "{}",
-            format!("| {:^16} | {:^16}", "Elapsed A", "Elapsed B").cyan()
+            format!(
+                "| {:^16} | {:^16} | {:^16}",
+                "Elapsed parse", "Elapsed A", "Elapsed B"
+            )
+            .cyan()
         );
     }
 
@@ -72,8 +102,8 @@ fn main() -> Result<(), Box<dyn Error>> {
         print!(
             "{}",
             format!(
-                "| {:^16} | {:^16} | {:^16} | {:^16} ",
-                "A bytes used", "A peak bytes", "B bytes used", "B peak bytes",
+                "| {:^16} | {:^16} | {:^16} | {:^16} | {:^16} ",
+                "Parse bytes used", "A bytes used", "A peak bytes", "B bytes used", "B peak bytes",
             )
             .cyan()
         );
@@ -81,11 +111,15 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     println!();
 
+    #[cfg(not(feature = "memory-profile"))]
+    let mut total_parse = Duration::ZERO;
     #[cfg(not(feature = "memory-profile"))]
     let mut total_a = Duration::ZERO;
     #[cfg(not(feature = "memory-profile"))]
     let mut total_b = Duration::ZERO;
 
+    #[cfg(feature = "memory-profile")]
+    let mut memory_total_parse: u64 = 0;
     #[cfg(feature = "memory-profile")]
     let mut memory_total_a: u64 = 0;
     #[cfg(feature = "memory-profile")]
@@ -100,45 +134,116 @@ fn main() -> Result<(), Box<dyn Error>> {
         .clone()
         .map(|out| csv::WriterBuilder::new().from_path(out).unwrap());
 
-    let client = AocClient::default();
-
-    for day in args.day.map(|day| day..=day).unwrap_or(1..=25) {
-        let solver = match get_solver((year, day).into()) {
+    let mut builder = AocClient::builder();
+    if args.offline {
+        builder = builder.offline(true);
+    }
+    if let Some(profile) = &args.profile {
+        builder = builder.profile(profile);
+    }
+    let data_dir = builder.resolved_data_dir()?;
+    let inputs = input_provider(builder)?;
+    let problems: Vec<Problem> = match (args.year, args.day) {
+        (Some(year), Some(day)) => vec![(year, day).into()],
+        (Some(year), None) => implemented_days(year)
+            .into_iter()
+            .map(|day| (year, day).into())
+            .collect(),
+        (None, _) => implemented_problems(),
+    };
+    let mut known_answers = HashMap::new();
+    if args.verify {
+        for year in problems.iter().map(|problem| *problem.year()).unique() {
+            known_answers.insert(year, AnswerMap::load(&data_dir, year)?);
+        }
+    }
+    let known = |problem: Problem, level: Level| {
+        known_answers
+            .get(problem.year())
+            .and_then(|answers| answers.get_answer(*problem.day(), level))
+    };
+    // Days with a known answer that produce no answer at all, e.g. as the input
+    // is missing or cannot be parsed, also fail the verification.
+    let missing_answers = |problem: Problem| {
+        let missing = [Level::A, Level::B]
+            .into_iter()
+            .filter(|level| known(problem, *level).is_some())
+            .count();
+        if missing > 0 {
+            println!(
+                "        | {}",
+                format!("{missing} known answer(s) could not be verified").red()
+            );
+        }
+        missing
+    };
+    let mut changed_answers = 0;
+
+    for (index, problem) in problems.iter().copied().enumerate() {
+        let (year, day) = (*problem.year(), *problem.day());
+        if args.year.is_none() && (index == 0 || *problems[index - 1].year() != year) {
+            println!("{}", format!("{year:?}").cyan());
+        }
+        let solver = match get_solver(problem) {
             Some(solver) => solver,
-            None => continue,
+            None => {
+                println!("Day {day: >2} \t| {}", "No solver registered".yellow());
+                continue;
+            }
+        };
+        let problem_input = match inputs.get_input(problem) {
+            Ok(input) => input,
+            Err(err) => {
+                println!("Day {day: >2} \t| {}", format!("No input: {err}").red());
+                changed_answers += missing_answers(problem);
+                continue;
+            }
         };
-        let problem_input = client
-            .get_input((year, day).into())
-            .unwrap_or_else(|_| panic!("no input for {year:?}/{day} was found"));
 
-        let (answer_a, stats_a) = benchmark(args.iterations, &problem_input, |s| solver.solve_a(s));
-        let (answer_b, stats_b) = benchmark(args.iterations, &problem_input, |s| solver.solve_b(s));
+        let (input, stats_parse) = benchmark(args.iterations, || solver.parse(&problem_input));
+        let input = match input {
+            Ok(input) => input,
+            Err(err) => {
+                println!("Day {day: >2} \t| {err}");
+                changed_answers += missing_answers(problem);
+                continue;
+            }
+        };
+        let (answer_a, stats_a) = benchmark(args.iterations, || solver.solve_a(&input));
+        let (answer_b, stats_b) = benchmark(args.iterations, || solver.solve_b(&input));
 
         print!(
-            "Day {day: >2} \t| {:>ANSWER_WIDTH$} | {:>ANSWER_WIDTH$} ",
-            answer_a.clone().map(|x| x.to_string()).unwrap_or_default(),
-            answer_b.clone().map(|x| x.to_string()).unwrap_or_default(),
+            "Day {day: >2} \t| {} | {} ",
+            format_answer(&answer_a, known(problem, Level::A)),
+            format_answer(&answer_b, known(problem, Level::B)),
         );
 
         #[cfg(not(feature = "memory-profile"))]
         {
             print!(
-                "| {elapsed_a:>16?} | {elapsed_b:>16?} ",
+                "| {elapsed_parse:>16?} | {elapsed_a:>16?} | {elapsed_b:>16?} ",
+                elapsed_parse = stats_parse.duration,
                 elapsed_a = stats_a.duration,
                 elapsed_b = stats_b.duration
             );
 
+            total_parse += stats_parse.duration;
             total_a += stats_a.duration;
             total_b += stats_b.duration;
         }
 
         #[cfg(feature = "memory-profile")]
         {
+            print!(
+                "| {:>#16.6} ",
+                Byte::from(stats_parse.memory_stats.total_bytes)
+            );
             print!("| {:>#16.6} ", Byte::from(stats_a.memory_stats.total_bytes));
             print!("| {:>#16.6} ", Byte::from(stats_a.memory_stats.max_bytes));
             print!("| {:>#16.6} ", Byte::from(stats_b.memory_stats.total_bytes));
             print!("| {:>#16.6} ", Byte::from(stats_b.memory_stats.max_bytes));
 
+            memory_total_parse += stats_parse.memory_stats.total_bytes;
             memory_total_a += stats_a.memory_stats.total_bytes;
             memory_peak_a = memory_peak_a.max(stats_a.memory_stats.max_bytes);
             memory_total_b += stats_b.memory_stats.total_bytes;
@@ -146,12 +251,34 @@ fn main() -> Result<(), Box<dyn Error>> {
         }
 
         println!();
+        for err in [&answer_a, &answer_b]
+            .into_iter()
+            .filter_map(|x| x.as_ref().err())
+        {
+            if !matches!(
+                err,
+                SolutionError::NotImplemented | SolutionError::NoPartTwo
+            ) {
+                println!("        | {err}");
+            }
+        }
+        for (answer, level) in [(&answer_a, Level::A), (&answer_b, Level::B)] {
+            if let Some(expected) = changed_answer(answer, known(problem, level)) {
+                changed_answers += 1;
+                println!(
+                    "        | {}",
+                    format!("Part {level:?} changed, expected {expected}").red()
+                );
+            }
+        }
 
         if args.output.is_some() {
             let bench = Benchmark {
+                year: year.as_int(),
                 day,
-                answer_a,
-                answer_b,
+                answer_a: answer_a.ok(),
+                answer_b: answer_b.ok(),
+                elapsed_parse: stats_parse.duration.as_nanos(),
                 elapsed_a: stats_a.duration.as_nanos(),
                 elapsed_b: stats_b.duration.as_nanos(),
             };
@@ -167,15 +294,19 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     #[cfg(not(feature = "memory-profile"))]
     {
-        println!("{}", format!("| {total_a:>16?} | {total_b:>16?} ").green());
         println!(
-            "Total time for both parts: {}",
-            format!("{:?}", total_a + total_b).green()
+            "{}",
+            format!("| {total_parse:>16?} | {total_a:>16?} | {total_b:>16?} ").green()
+        );
+        println!(
+            "Total time for parsing and both parts: {}",
+            format!("{:?}", total_parse + total_a + total_b).green()
         );
     }
 
     #[cfg(feature = "memory-profile")]
     {
+        print!("| {:>#16.6} ", Byte::from(memory_total_parse));
         print!("| {:>#16.6} ", Byte::from(memory_total_a));
         print!("| {:>#16.6} ", Byte::from(memory_peak_a));
         print!("| {:>#16.6} ", Byte::from(memory_total_b));
@@ -189,15 +320,50 @@ fn main() -> Result<(), Box<dyn Error>> {
         );
     }
 
+    if changed_answers > 0 {
+        return Err(format!("{changed_answers} known answer(s) are changed or missing").into());
+    }
+
     Ok(())
 }
 
+/// Format an answer to fit in a column of the table. Errors are only shown
+/// by kind here, and the full error is printed below the row.
+///
+/// If the correct answer is known, the answer is colored by whether it matches.
+fn format_answer(answer: &SolutionResult, known: Option<&str>) -> String {
+    let cell = match answer {
+        Ok(answer) => format!("{:>ANSWER_WIDTH$}", answer.to_string()),
+        Err(SolutionError::NotImplemented) => format!("{:>ANSWER_WIDTH$}", ""),
+        Err(err @ SolutionError::NoPartTwo) => {
+            return format!("{:>ANSWER_WIDTH$}", err.kind())
+                .dimmed()
+                .to_string()
+        }
+        Err(err) => return format!("{:>ANSWER_WIDTH$}", err.kind()).red().to_string(),
+    };
+
+    match known {
+        Some(_) if changed_answer(answer, known).is_some() => cell.red().to_string(),
+        Some(_) => cell.green().to_string(),
+        None => cell,
+    }
+}
+
+/// Compare an answer with the known correct answer, returning the correct
+/// answer if they differ.
+fn changed_answer<'a>(answer: &SolutionResult, known: Option<&'a str>) -> Option<&'a str> {
+    known.filter(|known| !matches!(answer, Ok(answer) if answer.to_string() == *known))
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "PascalCase")]
 struct Benchmark {
+    year: u16,
     day: u8,
     answer_a: Option<Answer>,
     answer_b: Option<Answer>,
+    elapsed_parse: u128,
     elapsed_a: u128,
     elapsed_b: u128,
 }
@@ -219,19 +385,15 @@ static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
 #[global_allocator]
 static ALLOC: dhat::Alloc = dhat::Alloc;
 
-fn benchmark<F>(
-    iterations: u32,
-    problem_input: &str,
-    solver: F,
-) -> (Option<Answer>, BenchmarkStatistics)
+fn benchmark<T, F>(iterations: u32, run: F) -> (T, BenchmarkStatistics)
 where
-    F: Fn(&str) -> Option<Answer>,
+    F: Fn() -> T,
 {
-    // Run solver once to warm up CPU cache.
-    _ = solver(problem_input);
+    // Run once to warm up CPU cache.
+    _ = run();
 
     let mut total = Duration::ZERO;
-    let mut answer: Option<Answer> = None;
+    let mut result: Option<T> = None;
     #[cfg(feature = "memory-profile")]
     let mut memory_stats: Option<dhat::HeapStats> = None;
 
@@ -240,7 +402,7 @@ where
         let _profiler = dhat::Profiler::builder().testing().build();
 
         let start = Instant::now();
-        let a = solver(problem_input);
+        let a = run();
         total += start.elapsed();
 
         #[cfg(feature = "memory-profile")]
@@ -248,13 +410,13 @@ where
             memory_stats = Some(dhat::HeapStats::get());
         }
 
-        if answer.is_none() {
-            answer = a;
+        if result.is_none() {
+            result = Some(a);
         }
     }
 
     (
-        answer,
+        result.expect("at least one iteration must be run"),
         BenchmarkStatistics {
             duration: total / iterations,
             #[cfg(feature = "memory-profile")]
@@ -1,14 +1,92 @@
-use std::time::Instant;
+use std::{
+    cell::OnceCell,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    time::Instant,
+};
 
-use advent_of_code::solutions::{answer::Answer, get_solver};
-use advent_of_code_client::{AocClient, Level, Problem, Year};
-use clap::Parser;
+use advent_of_code::{
+    examples::Examples,
+    scaffold,
+    solutions::{
+        error::{SolutionError, SolutionResult},
+        get_solver, Solver,
+    },
+};
+use advent_of_code_client::{
+    render_dashboard, AocClient, AocClientBuilder, Level, Problem, Year, YearProgress,
+};
+use anyhow::{anyhow, Context};
+use clap::{Parser, Subcommand};
 use colored::Colorize;
 
 #[derive(Debug, Parser)]
-#[command(author, version, about)]
+#[command(
+    author,
+    version,
+    about,
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    args: Option<Args>,
+
+    /// Profile in the config file of `aoc` to use the token of. Each profile
+    /// has its own inputs, stars, and answers.
+    ///
+    /// If not provided it will default to `AOC_PROFILE`.
+    #[arg(short = 'p', long, global = true)]
+    profile: Option<String>,
+
+    /// Never send requests to Advent of Code, and only use cached inputs and
+    /// descriptions. Answers cannot be submitted.
+    ///
+    /// Can also be enabled by setting `AOC_OFFLINE`.
+    #[arg(long, global = true)]
+    offline: bool,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Create a new solution for a day from the template.
+    New(NewArgs),
+    /// Find the examples in the puzzle description and add them to the stored examples.
+    FetchExamples(ProblemArgs),
+    /// Show the stars, solutions and cached inputs for all years.
+    Status,
+}
+
+#[derive(Debug, clap::Args)]
+struct ProblemArgs {
+    year: Year,
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+impl ProblemArgs {
+    fn problem(&self) -> Problem {
+        (self.year, self.day).into()
+    }
+}
+
+#[derive(Debug, clap::Args)]
+struct NewArgs {
+    #[command(flatten)]
+    problem: ProblemArgs,
+
+    /// Download the input and store the examples from the puzzle description,
+    /// using the first one as the test input.
+    #[arg(short = 'f', long)]
+    fetch: bool,
+}
+
+#[derive(Debug, clap::Args)]
 struct Args {
-    #[arg(value_enum)]
     year: Year,
     #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
     day: u8,
@@ -17,6 +95,22 @@ struct Args {
     submit_a: bool,
     #[arg(short = 'b', long)]
     submit_b: bool,
+
+    /// Read the input from a file instead of from Advent of Code. Use `-` to read from stdin.
+    ///
+    /// Answers from a custom input cannot be submitted.
+    #[arg(short = 'i', long, conflicts_with_all = ["submit_a", "submit_b"])]
+    input: Option<PathBuf>,
+
+    /// Run the solution against the stored examples for the problem and
+    /// report whether the expected answers are produced.
+    #[arg(short = 'e', long, conflicts_with_all = ["input", "submit_a", "submit_b"])]
+    examples: bool,
+
+    /// Wait for the puzzle to unlock before fetching the input, and when an
+    /// answer is submitted too soon after the previous one, wait and submit it again.
+    #[arg(short = 'w', long)]
+    wait: bool,
 }
 
 impl Args {
@@ -30,50 +124,241 @@ impl Args {
 static ALLOC: dhat::Alloc = dhat::Alloc;
 
 fn main() -> anyhow::Result<()> {
-    let args = Args::parse();
+    let cli = Cli::parse();
+
+    let builder = client_builder(cli.profile.as_deref(), cli.offline);
+
+    match (cli.command, cli.args) {
+        (Some(Command::New(args)), _) => new_day(&builder, args),
+        (Some(Command::FetchExamples(args)), _) => {
+            fetch_examples(&builder, args.problem()).map(|_| ())
+        }
+        (Some(Command::Status), _) => {
+            let has_solver = |problem| get_solver(problem).is_some();
+            print!(
+                "{}",
+                render_dashboard(
+                    &YearProgress::load_all(&builder.resolved_data_dir()?)?,
+                    Some(&has_solver),
+                )
+            );
+            Ok(())
+        }
+        (None, args) => solve(
+            &builder,
+            args.expect("Arguments are required without a subcommand"),
+        ),
+    }
+}
+
+/// Builder for the client, using the token of the profile if one is given.
+fn client_builder(profile: Option<&str>, offline: bool) -> AocClientBuilder {
+    let builder = if offline {
+        AocClient::builder().offline(true)
+    } else {
+        AocClient::builder()
+    };
+    match profile {
+        Some(profile) => builder.profile(profile),
+        None => builder,
+    }
+}
+
+/// Create a new solution from the template, optionally fetching the input
+/// and example for it.
+fn new_day(builder: &AocClientBuilder, args: NewArgs) -> anyhow::Result<()> {
+    let problem = args.problem.problem();
+
+    let example = if args.fetch {
+        builder.clone().build()?.get_input(problem)?;
+        fetch_examples(builder, problem)?
+            .iter()
+            .next()
+            .map(|example| example.input.clone())
+    } else {
+        None
+    };
+
+    let path = scaffold::create_day(problem, example.as_deref())?;
+    println!("Created {}", path.display().to_string().green());
+
+    Ok(())
+}
+
+/// Add the examples found in the puzzle description to the stored examples.
+fn fetch_examples(builder: &AocClientBuilder, problem: Problem) -> anyhow::Result<Examples> {
+    let candidates = builder
+        .clone()
+        .build()?
+        .get_description(problem)?
+        .examples();
+    if candidates.is_empty() {
+        println!("{}", "No examples found in the puzzle description".yellow());
+    }
+
+    let mut examples = Examples::load(problem)?;
+    let mut changed = 0;
+    for candidate in candidates {
+        if examples.merge(candidate.into()) {
+            changed += 1;
+        }
+    }
+
+    if changed > 0 {
+        examples.save(problem)?;
+        println!(
+            "{}",
+            format!("Updated {changed} example(s) for {problem}").green()
+        );
+    }
+
+    Ok(examples)
+}
+
+/// Run the solution for a problem, and optionally submit the answers.
+fn solve(builder: &AocClientBuilder, args: Args) -> anyhow::Result<()> {
     let problem = args.problem();
-    let client = AocClient::default();
-
-    let solver = get_solver(problem).unwrap_or_else(|| panic!("no solver found for {problem}"));
-
-    let problem_input = client
-        .get_input(problem)
-        .unwrap_or_else(|_| panic!("no input for {problem} was found"));
-    let print_and_submit = |answer: Option<Answer>, level: Level| -> anyhow::Result<()> {
-        if let Some(answer) = answer {
-            println!("Part {level:?}:  {:>20}", answer.to_string().cyan());
-            let should_submit = match level {
-                Level::A => args.submit_a,
-                Level::B => args.submit_b,
-            };
+    // Only create the client when it is needed, as it requires a token.
+    let client = OnceCell::new();
+    let client = || -> anyhow::Result<&AocClient> {
+        if let Some(client) = client.get() {
+            return Ok(client);
+        }
+        let created = builder
+            .clone()
+            .build()?
+            .wait_when_too_recent(args.wait)
+            .wait_for_unlock(args.wait);
+        Ok(client.get_or_init(|| created))
+    };
+
+    let solver =
+        get_solver(problem).ok_or_else(|| anyhow!("no solution is registered for {problem}"))?;
 
-            if should_submit {
-                let result = client.submit(problem, level, &answer.to_string())?;
-                println!("{result}");
+    if args.examples {
+        return run_examples(problem, solver.as_ref());
+    }
+
+    let problem_input = match &args.input {
+        Some(path) => read_input(path)?,
+        None => client()?.get_input(problem)?,
+    };
+    let print_and_submit = |answer: SolutionResult, level: Level| -> anyhow::Result<()> {
+        let should_submit = match level {
+            Level::A => args.submit_a,
+            Level::B => args.submit_b,
+        };
+        let answer = match answer {
+            Ok(answer) => answer,
+            Err(err) => {
+                println!("Part {level:?}:  {err}");
+                // The second star of day 25 is given by finishing the year instead.
+                if err == SolutionError::NoPartTwo && should_submit {
+                    println!("{}", client()?.finish(*problem.year())?);
+                }
+                return Ok(());
             }
+        };
+
+        println!("Part {level:?}:  {:>20}", answer.to_string().cyan());
+        if should_submit {
+            let result = client()?.submit(problem, level, &answer.to_string())?;
+            println!("{result}");
         }
 
         Ok(())
     };
 
-    let answer_a = run_solver(|x| solver.solve_a(x), &problem_input);
+    let input = measure(|| solver.parse(&problem_input), Result::is_ok, "Parse")?;
+
+    let answer_a = measure(|| solver.solve_a(&input), Result::is_ok, "Time");
     print_and_submit(answer_a, Level::A)?;
 
-    let answer_b = run_solver(|x| solver.solve_b(x), &problem_input);
+    let answer_b = measure(|| solver.solve_b(&input), Result::is_ok, "Time");
     print_and_submit(answer_b, Level::B)?;
 
     Ok(())
 }
 
-fn run_solver<F: Fn(&str) -> Option<Answer>>(solve: F, input: &str) -> Option<Answer> {
+/// Run all stored examples for a problem, and report whether each part
+/// produced the expected answer.
+fn run_examples(problem: Problem, solver: &dyn Solver) -> anyhow::Result<()> {
+    let examples = Examples::load(problem)?;
+    if examples.is_empty() {
+        println!("{}", format!("No examples stored for {problem}").yellow());
+        return Ok(());
+    }
+
+    let mut failures = 0;
+    for (i, example) in examples.iter().enumerate() {
+        println!("Example {}", i + 1);
+        let results = match example.run(solver) {
+            Ok(results) => results,
+            Err(err) => {
+                println!("Parse:   {err}");
+                failures += 1;
+                continue;
+            }
+        };
+
+        for (result, level) in results.into_iter().zip([Level::A, Level::B]) {
+            let answer = match &result.answer {
+                Ok(answer) => format!("{:>20}", answer.to_string()),
+                Err(err) => err.to_string(),
+            };
+            match (result.passed(), result.expected) {
+                (Some(true), _) => {
+                    println!("Part {level:?}:  {} {}", answer.green(), "pass".green())
+                }
+                (Some(false), Some(expected)) => {
+                    failures += 1;
+                    println!(
+                        "Part {level:?}:  {} {} (expected {expected})",
+                        answer.red(),
+                        "fail".red()
+                    );
+                }
+                _ => println!("Part {level:?}:  {}", answer.cyan()),
+            }
+        }
+    }
+
+    if failures > 0 {
+        anyhow::bail!("{failures} example(s) failed");
+    }
+
+    Ok(())
+}
+
+/// Read input from a file, or from stdin if the path is `-`.
+fn read_input(path: &Path) -> anyhow::Result<String> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .context("Failed to read input from stdin")?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path)
+            .with_context(|| format!("Failed to read input from {}", path.display()))
+    }
+}
+
+/// Run `f` while measuring the time it takes. Statistics are only printed
+/// if `should_print` returns `true` for the result.
+fn measure<T, F, P>(f: F, should_print: P, label: &str) -> T
+where
+    F: FnOnce() -> T,
+    P: Fn(&T) -> bool,
+{
     #[cfg(feature = "memory-profile")]
     let _profiler = dhat::Profiler::builder().testing().build();
 
-    let start_a = Instant::now();
-    let answer = solve(input);
-    let elapsed = start_a.elapsed();
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed();
 
-    if answer.is_some() {
+    if should_print(&result) {
         #[cfg(feature = "memory-profile")]
         {
             use byte_unit::Byte;
@@ -89,8 +374,25 @@ fn run_solver<F: Fn(&str) -> Option<Answer>>(solve: F, input: &str) -> Option<An
                 mem_stats.total_blocks, mem_stats.max_blocks,
             );
         }
-        println!("Time:    {elapsed:>20?}");
+        println!("{:<9}{elapsed:>20?}", format!("{label}:"));
     }
 
-    answer
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn solve_unimplemented_problem() {
+        let cli = Cli::parse_from(["solve", "2015", "1"]);
+
+        let result = solve(&client_builder(None, true), cli.args.unwrap());
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "no solution is registered for Y2015/1"
+        );
+    }
 }
@@ -0,0 +1,240 @@
+//! Store of puzzle examples and their expected answers.
+//!
+//! Examples for a problem are kept in `samples/<year>/<day>.toml`, where each
+//! example is an `[[example]]` table with the `input` and optionally the
+//! expected answers `a` and `b` for each part:
+//!
+//! ```toml
+//! [[example]]
+//! input = '''
+//! 1122
+//! '''
+//! a = "3"
+//! ```
+use std::{fs, io::ErrorKind, path::PathBuf};
+
+use advent_of_code_client::{ExampleCandidate, Level, Problem};
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::solutions::{error::SolutionResult, Solver};
+
+/// A single example input, with the answers expected for it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Example {
+    pub input: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub a: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub b: Option<String>,
+}
+
+impl Example {
+    /// Expected answer for a given part, if it is known.
+    pub fn expected(&self, level: Level) -> Option<&str> {
+        match level {
+            Level::A => self.a.as_deref(),
+            Level::B => self.b.as_deref(),
+        }
+    }
+
+    /// Run `solver` on the example. Only fails if the input cannot be parsed.
+    pub fn run(&self, solver: &dyn Solver) -> SolutionResult<[ExampleResult; 2]> {
+        let input = solver.parse(&self.input)?;
+
+        Ok([
+            ExampleResult::new(solver.solve_a(&input), self.a.clone()),
+            ExampleResult::new(solver.solve_b(&input), self.b.clone()),
+        ])
+    }
+}
+
+impl From<ExampleCandidate> for Example {
+    fn from(candidate: ExampleCandidate) -> Self {
+        Self {
+            input: candidate.input,
+            a: candidate.answer_a,
+            b: candidate.answer_b,
+        }
+    }
+}
+
+/// Outcome of running a solution for one part of an [Example].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExampleResult {
+    pub answer: SolutionResult,
+    pub expected: Option<String>,
+}
+
+impl ExampleResult {
+    fn new(answer: SolutionResult, expected: Option<String>) -> Self {
+        Self { answer, expected }
+    }
+
+    /// Whether the answer matches the expected one. `None` if no answer is expected.
+    pub fn passed(&self) -> Option<bool> {
+        self.expected.as_ref().map(|expected| {
+            self.answer
+                .as_ref()
+                .is_ok_and(|answer| answer.to_string() == *expected)
+        })
+    }
+}
+
+/// All the examples stored for a problem.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Examples {
+    #[serde(rename = "example", default)]
+    examples: Vec<Example>,
+}
+
+impl Examples {
+    /// Load the examples for a problem. A problem without any stored
+    /// examples gives an empty collection.
+    pub fn load(problem: Problem) -> anyhow::Result<Self> {
+        let path = Self::path(problem);
+        match fs::read_to_string(&path) {
+            Ok(content) => toml::from_str(&content)
+                .with_context(|| format!("Failed to parse examples in {}", path.display())),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err).context(format!("Failed to read {}", path.display())),
+        }
+    }
+
+    /// Store the examples for a problem, overwriting any existing ones.
+    pub fn save(&self, problem: Problem) -> anyhow::Result<()> {
+        let path = Self::path(problem);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, toml::to_string(self)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    pub fn push(&mut self, example: Example) {
+        self.examples.push(example);
+    }
+
+    /// Add an example, unless one with the same input is already stored, in
+    /// which case only its missing answers are filled in. Returns whether
+    /// anything changed.
+    pub fn merge(&mut self, example: Example) -> bool {
+        let Some(existing) = self
+            .examples
+            .iter_mut()
+            .find(|existing| existing.input.trim_end() == example.input.trim_end())
+        else {
+            self.push(example);
+            return true;
+        };
+
+        let before = existing.clone();
+        existing.a = existing.a.take().or(example.a);
+        existing.b = existing.b.take().or(example.b);
+        *existing != before
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Example> {
+        self.examples.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.examples.is_empty()
+    }
+
+    fn path(problem: Problem) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("samples")
+            .join(problem.year().as_int().to_string())
+            .join(format!("{}.toml", problem.day()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use advent_of_code_client::Year;
+
+    use super::*;
+    use crate::solutions::{get_solver, implemented_problems};
+
+    #[test]
+    fn parse_examples() {
+        let examples: Examples = toml::from_str(
+            r#"
+[[example]]
+input = '''
+1122
+'''
+a = "3"
+
+[[example]]
+input = "1234"
+b = "0"
+"#,
+        )
+        .unwrap();
+
+        let examples: Vec<_> = examples.iter().collect();
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].input, "1122\n");
+        assert_eq!(examples[0].expected(Level::A), Some("3"));
+        assert_eq!(examples[0].expected(Level::B), None);
+        assert_eq!(examples[1].expected(Level::B), Some("0"));
+    }
+
+    #[test]
+    fn merge_examples() {
+        let mut examples = Examples::default();
+        let example = |a: Option<&str>, b: Option<&str>| Example {
+            input: "1122\n".to_string(),
+            a: a.map(str::to_string),
+            b: b.map(str::to_string),
+        };
+
+        assert!(examples.merge(example(Some("3"), None)));
+        assert!(!examples.merge(example(Some("4"), None)));
+        assert!(examples.merge(example(None, Some("0"))));
+
+        assert_eq!(
+            examples.iter().collect::<Vec<_>>(),
+            vec![&example(Some("3"), Some("0"))]
+        );
+    }
+
+    #[test]
+    fn run_example() {
+        let example = Example {
+            input: "1122".to_string(),
+            a: Some("3".to_string()),
+            b: Some("42".to_string()),
+        };
+        let solver = get_solver(Problem::new(Year::Y2017, 1)).unwrap();
+
+        let [a, b] = example.run(solver.as_ref()).unwrap();
+        assert_eq!(a.passed(), Some(true));
+        assert_eq!(b.passed(), Some(false));
+    }
+
+    #[test]
+    fn all_stored_examples_pass() {
+        for problem in implemented_problems() {
+            let solver = get_solver(problem).unwrap();
+            for (i, example) in Examples::load(problem).unwrap().iter().enumerate() {
+                for (result, level) in example
+                    .run(solver.as_ref())
+                    .unwrap()
+                    .iter()
+                    .zip([Level::A, Level::B])
+                {
+                    assert_ne!(
+                        result.passed(),
+                        Some(false),
+                        "example {} for {problem}/{level:?}: {:?}",
+                        i + 1,
+                        result.answer
+                    );
+                }
+            }
+        }
+    }
+}
@@ -1,5 +1,7 @@
 #![feature(iter_map_windows)]
 #![feature(let_chains)]
 
+pub mod examples;
+pub mod scaffold;
 pub mod solutions;
 pub mod utils;
@@ -0,0 +1,142 @@
+//! Generation of new solutions from the template in `snippet.rs.txt`.
+//!
+//! A new day is written to `src/solutions/y<year>/day<day>.rs`, and the
+//! `pub mod` declarations for it, and for the year if it is new, are added.
+//! The solution registers itself with [crate::solutions::register_solver],
+//! so nothing else has to be wired up.
+use std::{
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use advent_of_code_client::Problem;
+use anyhow::{bail, Context};
+
+const TEMPLATE: &str = include_str!("../snippet.rs.txt");
+
+/// Create the solution for a problem from the template, with `example` as
+/// the test input if one is given. Returns the path of the new file.
+pub fn create_day(problem: Problem, example: Option<&str>) -> anyhow::Result<PathBuf> {
+    let solutions = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("solutions");
+    let year_module = year_module(problem);
+    let day_module = day_module(problem);
+    let path = solutions
+        .join(&year_module)
+        .join(format!("{day_module}.rs"));
+
+    if path.exists() {
+        bail!(
+            "A solution for {problem} already exists at {}",
+            path.display()
+        );
+    }
+
+    fs::create_dir_all(path.parent().expect("Day module is always in a directory"))?;
+    fs::write(&path, render(problem, example))
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+
+    add_module(&solutions.join(format!("{year_module}.rs")), &day_module)?;
+    add_module(&solutions.with_extension("rs"), &year_module)?;
+
+    Ok(path)
+}
+
+/// Render the template for a problem.
+pub fn render(problem: Problem, example: Option<&str>) -> String {
+    let content = TEMPLATE
+        .replace("DayX", &format!("Day{:02}", problem.day()))
+        .replace("2023", &problem.year().as_int().to_string())
+        .replace(", X", &format!(", {}", problem.day()));
+
+    match example {
+        Some(example) => content.replace(
+            r##"const INPUT: &str = r#""#;"##,
+            &format!("const INPUT: &str = r#\"{}\"#;", example.trim_end()),
+        ),
+        None => content,
+    }
+}
+
+fn year_module(problem: Problem) -> String {
+    format!("y{}", problem.year().as_int())
+}
+
+fn day_module(problem: Problem) -> String {
+    format!("day{:02}", problem.day())
+}
+
+/// Add `pub mod <module>;` to a file, keeping the declarations sorted.
+/// The file is created if it does not exist.
+fn add_module(path: &Path, module: &str) -> anyhow::Result<()> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(err).context(format!("Failed to read {}", path.display())),
+    };
+
+    fs::write(path, insert_module(&content, module))
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+fn insert_module(content: &str, module: &str) -> String {
+    let declaration = format!("pub mod {module};");
+    let mut lines: Vec<&str> = content.lines().collect();
+    if lines.contains(&declaration.as_str()) {
+        return content.to_string();
+    }
+
+    let modules = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.starts_with("pub mod "));
+    let index = match modules
+        .clone()
+        .find(|(_, line)| **line > declaration.as_str())
+    {
+        Some((index, _)) => index,
+        None => modules
+            .map(|(index, _)| index + 1)
+            .max()
+            .unwrap_or(lines.len()),
+    };
+    lines.insert(index, &declaration);
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod test {
+    use advent_of_code_client::Year;
+
+    use super::*;
+
+    #[test]
+    fn render_template() {
+        let content = render(Problem::new(Year::Y2016, 7), Some("abc\ndef\n"));
+
+        assert!(content.contains("pub struct Day07;"));
+        assert!(content.contains("register_solver!(2016, 7, Day07);"));
+        assert!(content.contains("Problem::new(Year::from_const(2016), 7)"));
+        assert!(content.contains("const INPUT: &str = r#\"abc\ndef\"#;"));
+        assert!(!content.contains("DayX"));
+    }
+
+    #[test]
+    fn insert_module_sorted() {
+        let content = "pub mod day01;\npub mod day03;\n";
+
+        assert_eq!(
+            insert_module(content, "day02"),
+            "pub mod day01;\npub mod day02;\npub mod day03;\n"
+        );
+        assert_eq!(
+            insert_module(content, "day04"),
+            "pub mod day01;\npub mod day03;\npub mod day04;\n"
+        );
+        assert_eq!(insert_module(content, "day03"), content);
+        assert_eq!(insert_module("", "day01"), "pub mod day01;\n");
+    }
+}
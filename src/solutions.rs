@@ -1,84 +1,233 @@
+use std::any::Any;
+
 use advent_of_code_client::{Problem, Year};
 
-use self::answer::Answer;
+use self::{
+    answer::Answer,
+    error::{catch_panic, SolutionError, SolutionResult},
+};
 
 pub mod answer;
+pub mod error;
 pub mod y2017;
 pub mod y2022;
 pub mod y2023;
 
+/// Solution working directly on the raw input. Returning `None` indicates
+/// that the part has not been implemented yet.
 pub trait Solution {
     fn solve_a(&self, input: &str) -> Option<Answer>;
     fn solve_b(&self, input: &str) -> Option<Answer>;
 }
 
-pub fn get_solver(problem: Problem) -> Option<Box<dyn Solution>> {
-    match problem.year() {
-        Year::Y2017 => match problem.day() {
-            1 => Some(Box::new(y2017::day01::Day01 {})),
-            2 => Some(Box::new(y2017::day02::Day02 {})),
-            3 => Some(Box::new(y2017::day03::Day03 {})),
-            4 => Some(Box::new(y2017::day04::Day04 {})),
-            5 => Some(Box::new(y2017::day05::Day05 {})),
-            6 => Some(Box::new(y2017::day06::Day06 {})),
-            7 => Some(Box::new(y2017::day07::Day07 {})),
-            8 => Some(Box::new(y2017::day08::Day08 {})),
-            9 => Some(Box::new(y2017::day09::Day09 {})),
-            10 => Some(Box::new(y2017::day10::Day10 {})),
-            11 => Some(Box::new(y2017::day11::Day11 {})),
-            12 => Some(Box::new(y2017::day12::Day12 {})),
-            13 => Some(Box::new(y2017::day13::Day13 {})),
-            14 => Some(Box::new(y2017::day14::Day14 {})),
-            15 => Some(Box::new(y2017::day15::Day15 {})),
-            16 => Some(Box::new(y2017::day16::Day16 {})),
-            17 => Some(Box::new(y2017::day17::Day17 {})),
-            18 => Some(Box::new(y2017::day18::Day18 {})),
-            19 => Some(Box::new(y2017::day19::Day19 {})),
-            20 => Some(Box::new(y2017::day20::Day20 {})),
-            21 => Some(Box::new(y2017::day21::Day21 {})),
-            22 => Some(Box::new(y2017::day22::Day22 {})),
-            23 => Some(Box::new(y2017::day23::Day23 {})),
-            24 => Some(Box::new(y2017::day24::Day24 {})),
-            25 => Some(Box::new(y2017::day25::Day25 {})),
-            _ => None,
-        },
-        Year::Y2022 => match problem.day() {
-            1 => Some(Box::new(y2022::day01::Day01 {})),
-            2 => Some(Box::new(y2022::day02::Day02 {})),
-            3 => Some(Box::new(y2022::day03::Day03 {})),
-            4 => Some(Box::new(y2022::day04::Day04 {})),
-            5 => Some(Box::new(y2022::day05::Day05 {})),
-            6 => Some(Box::new(y2022::day06::Day06 {})),
-            7 => Some(Box::new(y2022::day07::Day07 {})),
-            8 => Some(Box::new(y2022::day08::Day08 {})),
-            9 => Some(Box::new(y2022::day09::Day09 {})),
-            10 => Some(Box::new(y2022::day10::Day10 {})),
-            11 => Some(Box::new(y2022::day11::Day11 {})),
-            12 => Some(Box::new(y2022::day12::Day12 {})),
-            13 => Some(Box::new(y2022::day13::Day13 {})),
-            14 => Some(Box::new(y2022::day14::Day14 {})),
-            15 => Some(Box::new(y2022::day15::Day15 {})),
-            16 => Some(Box::new(y2022::day16::Day16 {})),
-            _ => None,
-        },
-        Year::Y2023 => match problem.day() {
-            1 => Some(Box::new(y2023::day01::Day01)),
-            2 => Some(Box::new(y2023::day02::Day02)),
-            3 => Some(Box::new(y2023::day03::Day03)),
-            4 => Some(Box::new(y2023::day04::Day04)),
-            5 => Some(Box::new(y2023::day05::Day05)),
-            6 => Some(Box::new(y2023::day06::Day06)),
-            7 => Some(Box::new(y2023::day07::Day07)),
-            8 => Some(Box::new(y2023::day08::Day08)),
-            9 => Some(Box::new(y2023::day09::Day09)),
-            10 => Some(Box::new(y2023::day10::Day10)),
-            11 => Some(Box::new(y2023::day11::Day11)),
-            12 => Some(Box::new(y2023::day12::Day12)),
-            13 => Some(Box::new(y2023::day13::Day13)),
-            14 => Some(Box::new(y2023::day14::Day14)),
-            15 => Some(Box::new(y2023::day15::Day15)),
-            _ => None,
-        },
-        _ => None,
+/// Solution where the input is parsed once into [ParsedSolution::Input],
+/// which is then shared between both parts.
+///
+/// All implementations of [Solution] are also a `ParsedSolution`, where
+/// the input is simply the raw text and missing answers are reported as
+/// [SolutionError::NotImplemented].
+pub trait ParsedSolution {
+    type Input;
+
+    fn parse(&self, input: &str) -> SolutionResult<Self::Input>;
+    fn solve_a(&self, input: &Self::Input) -> SolutionResult;
+    fn solve_b(&self, input: &Self::Input) -> SolutionResult;
+}
+
+impl<T: Solution> ParsedSolution for T {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> SolutionResult<Self::Input> {
+        Ok(input.to_owned())
+    }
+
+    fn solve_a(&self, input: &Self::Input) -> SolutionResult {
+        Solution::solve_a(self, input).ok_or(SolutionError::NotImplemented)
+    }
+
+    fn solve_b(&self, input: &Self::Input) -> SolutionResult {
+        Solution::solve_b(self, input).ok_or(SolutionError::NotImplemented)
+    }
+}
+
+/// Input that has been parsed by a [Solver]. The concrete type is only
+/// known by the solver that produced it.
+pub type ParsedInput = Box<dyn Any>;
+
+/// Object safe version of [ParsedSolution], which is what the registry
+/// hands out, so solutions with different input types can be run the same way.
+///
+/// Panics in the underlying solution are caught and returned as
+/// [SolutionError::Panicked].
+pub trait Solver {
+    fn parse(&self, input: &str) -> SolutionResult<ParsedInput>;
+    fn solve_a(&self, input: &ParsedInput) -> SolutionResult;
+    fn solve_b(&self, input: &ParsedInput) -> SolutionResult;
+}
+
+impl<T> Solver for T
+where
+    T: ParsedSolution,
+    T::Input: 'static,
+{
+    fn parse(&self, input: &str) -> SolutionResult<ParsedInput> {
+        catch_panic(|| {
+            ParsedSolution::parse(self, input).map(|parsed| Box::new(parsed) as ParsedInput)
+        })
+    }
+
+    fn solve_a(&self, input: &ParsedInput) -> SolutionResult {
+        catch_panic(|| ParsedSolution::solve_a(self, downcast_input::<T>(input)))
+    }
+
+    fn solve_b(&self, input: &ParsedInput) -> SolutionResult {
+        catch_panic(|| ParsedSolution::solve_b(self, downcast_input::<T>(input)))
+    }
+}
+
+/// Solver for a problem with only one part, i.e. day 25, where part two is
+/// reported as [SolutionError::NoPartTwo] instead of being run.
+struct SinglePart(Box<dyn Solver>);
+
+impl Solver for SinglePart {
+    fn parse(&self, input: &str) -> SolutionResult<ParsedInput> {
+        self.0.parse(input)
+    }
+
+    fn solve_a(&self, input: &ParsedInput) -> SolutionResult {
+        self.0.solve_a(input)
+    }
+
+    fn solve_b(&self, _input: &ParsedInput) -> SolutionResult {
+        Err(SolutionError::NoPartTwo)
+    }
+}
+
+fn downcast_input<T: ParsedSolution>(input: &ParsedInput) -> &T::Input
+where
+    T::Input: 'static,
+{
+    input
+        .downcast_ref()
+        .expect("input must be parsed by the same solver")
+}
+
+/// Entry in the solver registry, connecting a [Problem] to the solution for it.
+///
+/// Entries are not created directly, but through the [register_solver] macro,
+/// which should be invoked next to the solution for each day.
+pub struct SolverEntry {
+    problem: Problem,
+    create: fn() -> Box<dyn Solver>,
+}
+
+impl SolverEntry {
+    #[doc(hidden)]
+    pub const fn new(problem: Problem, create: fn() -> Box<dyn Solver>) -> Self {
+        Self { problem, create }
+    }
+
+    /// The problem this entry provides a solution for.
+    pub fn problem(&self) -> Problem {
+        self.problem
+    }
+
+    /// Create a new instance of the solver.
+    pub fn solver(&self) -> Box<dyn Solver> {
+        (self.create)()
+    }
+}
+
+inventory::collect!(SolverEntry);
+
+/// Register a solution for a given year and day, so it can be found with [get_solver].
+///
+/// ```ignore
+/// pub struct Day01;
+///
+/// register_solver!(2023, 1, Day01);
+/// ```
+macro_rules! register_solver {
+    ($year:literal, $day:literal, $solver:ident) => {
+        inventory::submit! {
+            $crate::solutions::SolverEntry::new(
+                advent_of_code_client::Problem::new(
+                    advent_of_code_client::Year::from_const($year),
+                    $day,
+                ),
+                || Box::new($solver {}),
+            )
+        }
+    };
+}
+pub(crate) use register_solver;
+
+/// Get the solver for a problem, if one has been registered.
+pub fn get_solver(problem: Problem) -> Option<Box<dyn Solver>> {
+    let solver = inventory::iter::<SolverEntry>
+        .into_iter()
+        .find(|entry| entry.problem == problem)
+        .map(SolverEntry::solver)?;
+
+    if problem.has_part_two() {
+        Some(solver)
+    } else {
+        Some(Box::new(SinglePart(solver)))
+    }
+}
+
+/// All problems that has a registered solver, sorted by year and day.
+pub fn implemented_problems() -> Vec<Problem> {
+    let mut problems: Vec<Problem> = inventory::iter::<SolverEntry>
+        .into_iter()
+        .map(SolverEntry::problem)
+        .collect();
+    problems.sort_by_key(|p| (p.year().as_int(), *p.day()));
+    problems
+}
+
+/// All days that has a registered solver for the given year, in ascending order.
+pub fn implemented_days(year: Year) -> Vec<u8> {
+    implemented_problems()
+        .into_iter()
+        .filter(|p| *p.year() == year)
+        .map(|p| *p.day())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+
+    use super::*;
+
+    #[test]
+    fn registered_problems_are_unique() {
+        let problems = implemented_problems();
+        assert!(!problems.is_empty());
+        assert!(problems
+            .iter()
+            .map(|p| (p.year().as_int(), *p.day()))
+            .all_unique());
+    }
+
+    #[test]
+    fn get_solver_for_registered_problem() {
+        assert!(get_solver(Problem::new(Year::Y2017, 1)).is_some());
+        assert!(get_solver(Problem::new(Year::Y2016, 1)).is_none());
+    }
+
+    #[test]
+    fn last_day_has_no_part_two() {
+        let solver = get_solver(Problem::new(Year::Y2017, 25)).unwrap();
+        let input = solver.parse("").unwrap();
+
+        assert_eq!(solver.solve_b(&input), Err(SolutionError::NoPartTwo));
+    }
+
+    #[test]
+    fn implemented_days_are_sorted() {
+        let days = implemented_days(Year::Y2017);
+        assert_eq!(days, (1..=25).collect::<Vec<_>>());
     }
 }
@@ -0,0 +1,174 @@
+use std::{
+    any::Any,
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+};
+
+use colored::Colorize;
+
+use super::answer::Answer;
+
+/// Result of parsing or solving a part of a problem.
+pub type SolutionResult<T = Answer> = Result<T, SolutionError>;
+
+/// Reasons a solution can fail to produce an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolutionError {
+    /// The part has not been implemented yet.
+    NotImplemented,
+    /// The input could not be parsed. `line` and `column` are one-indexed.
+    MalformedInput {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input was valid, but no answer exists for it.
+    NoSolution(String),
+    /// The solution panicked while running.
+    Panicked(String),
+    /// The problem only has one part, which is the case for day 25.
+    NoPartTwo,
+}
+
+impl SolutionError {
+    /// Create an error for malformed input at a zero-indexed line and column.
+    pub fn malformed(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self::MalformedInput {
+            line: line + 1,
+            column: column + 1,
+            message: message.into(),
+        }
+    }
+
+    /// Create an error for when no answer exists for the input.
+    pub fn no_solution(message: impl Into<String>) -> Self {
+        Self::NoSolution(message.into())
+    }
+
+    /// Short description of the kind of error, without any details.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            SolutionError::NotImplemented => "not implemented",
+            SolutionError::MalformedInput { .. } => "malformed input",
+            SolutionError::NoSolution(_) => "no solution",
+            SolutionError::Panicked(_) => "panicked",
+            SolutionError::NoPartTwo => "no part two",
+        }
+    }
+}
+
+impl Display for SolutionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolutionError::NotImplemented => write!(f, "{}", "Not implemented".dimmed()),
+            SolutionError::MalformedInput {
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "{}",
+                format!("Malformed input at line {line}, column {column}: {message}").red()
+            ),
+            SolutionError::NoSolution(message) => {
+                write!(f, "{}", format!("No solution found: {message}").yellow())
+            }
+            SolutionError::Panicked(message) => {
+                write!(f, "{}", format!("Solution panicked: {message}").red())
+            }
+            SolutionError::NoPartTwo => write!(f, "{}", "No part two".dimmed()),
+        }
+    }
+}
+
+impl std::error::Error for SolutionError {}
+
+/// Error from parsing a single line of the input. The line number is added
+/// by [parse_lines] when turning it into a [SolutionError::MalformedInput].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineError {
+    column: usize,
+    message: String,
+}
+
+impl LineError {
+    /// Create an error at a zero-indexed column.
+    pub fn new(column: usize, message: impl Into<String>) -> Self {
+        Self {
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// Create an error pointing at `token`, which must be a subslice of `line`.
+    pub fn at(line: &str, token: &str, message: impl Into<String>) -> Self {
+        let column = (token.as_ptr() as usize).saturating_sub(line.as_ptr() as usize);
+        debug_assert!(column <= line.len(), "token must be a part of the line");
+        Self::new(column, message)
+    }
+
+    /// Turn the error into a [SolutionError::MalformedInput] on a zero-indexed line.
+    pub fn on_line(self, line: usize) -> SolutionError {
+        SolutionError::malformed(line, self.column, self.message)
+    }
+}
+
+/// Parse each line of `input` with `parse`, reporting the position of the
+/// first line that fails.
+pub fn parse_lines<T, F>(input: &str, parse: F) -> SolutionResult<Vec<T>>
+where
+    F: Fn(&str) -> Result<T, LineError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse(line).map_err(|e| e.on_line(index)))
+        .collect()
+}
+
+/// Run `f`, turning any panic into a [SolutionError::Panicked], so a single
+/// bad solution does not take down the whole run.
+pub(crate) fn catch_panic<T, F>(f: F) -> SolutionResult<T>
+where
+    F: FnOnce() -> SolutionResult<T>,
+{
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|payload| Err(SolutionError::Panicked(panic_message(payload.as_ref()))))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_lines_reports_position() {
+        let input = "1\n2\nx3";
+        let result = parse_lines(input, |line| {
+            line.parse::<u32>()
+                .map_err(|_| LineError::at(line, &line[1..], "not a number"))
+        });
+
+        assert_eq!(
+            result,
+            Err(SolutionError::MalformedInput {
+                line: 3,
+                column: 2,
+                message: "not a number".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn catch_panic_with_message() {
+        let result: SolutionResult<()> = catch_panic(|| panic!("oh no"));
+        assert_eq!(result, Err(SolutionError::Panicked("oh no".to_string())));
+    }
+}
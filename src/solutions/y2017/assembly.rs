@@ -0,0 +1,86 @@
+//! Registers and instruction arguments for the assembly-like programs of
+//! day 18 and day 23, which only differ in their instructions.
+use std::{collections::HashMap, str::Split};
+
+use crate::solutions::error::LineError;
+
+pub type Registers = HashMap<char, i64>;
+
+#[derive(PartialEq, Debug)]
+pub enum RegOrVal {
+    Register(char),
+    Value(i64),
+}
+
+impl RegOrVal {
+    pub fn get_value(&self, registers: &Registers) -> i64 {
+        match self {
+            RegOrVal::Register(r) => *registers.get(r).unwrap_or(&0),
+            RegOrVal::Value(n) => *n,
+        }
+    }
+}
+
+impl TryFrom<&str> for RegOrVal {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.parse::<i64>() {
+            Ok(n) => Ok(RegOrVal::Value(n)),
+            Err(_) => match value.chars().collect::<Vec<_>>()[..] {
+                [c] if c.is_ascii_lowercase() => Ok(RegOrVal::Register(c)),
+                _ => Err(format!("'{value}' is neither a register nor a number")),
+            },
+        }
+    }
+}
+
+/// Arguments of an instruction, which are parsed one at a time.
+pub struct Arguments<'a> {
+    line: &'a str,
+    tokens: Split<'a, char>,
+}
+
+impl<'a> Arguments<'a> {
+    /// Parse the next argument, which must be a register or a number.
+    pub fn arg(&mut self) -> Result<RegOrVal, LineError> {
+        let token = self.token()?;
+        RegOrVal::try_from(token).map_err(|message| LineError::at(self.line, token, message))
+    }
+
+    /// Parse the next argument, which must be a register, e.g. as it is written to.
+    pub fn register(&mut self) -> Result<char, LineError> {
+        let token = self.token()?;
+        match RegOrVal::try_from(token) {
+            Ok(RegOrVal::Register(register)) => Ok(register),
+            _ => Err(LineError::at(self.line, token, "expected a register")),
+        }
+    }
+
+    /// Check that all arguments have been parsed.
+    pub fn end(mut self) -> Result<(), LineError> {
+        match self.tokens.next() {
+            Some(token) => Err(LineError::at(self.line, token, "unexpected argument")),
+            None => Ok(()),
+        }
+    }
+
+    fn token(&mut self) -> Result<&'a str, LineError> {
+        self.tokens
+            .next()
+            .ok_or_else(|| LineError::new(self.line.len(), "missing argument"))
+    }
+}
+
+/// Split an instruction into its name and its arguments.
+pub fn parse_instruction(line: &str) -> (&str, Arguments<'_>) {
+    let mut tokens = line.split(' ');
+    let name = tokens.next().unwrap_or_default();
+
+    (name, Arguments { line, tokens })
+}
+
+/// Error for an instruction that does not exist.
+pub fn unsupported(name: &str) -> LineError {
+    LineError::new(0, format!("instruction '{name}' not supported"))
+}
@@ -1,9 +1,11 @@
 use itertools::Itertools;
 
-use crate::solutions::{Answer, Solution};
+use crate::solutions::{register_solver, Answer, Solution};
 
 pub struct Day01 {}
 
+register_solver!(2017, 1, Day01);
+
 impl Solution for Day01 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         fn compare(a: u32, b: u32) -> u32 {
@@ -1,9 +1,11 @@
 use itertools::{Itertools, MinMaxResult};
 
-use crate::solutions::{Answer, Solution};
+use crate::solutions::{register_solver, Answer, Solution};
 
 pub struct Day02 {}
 
+register_solver!(2017, 2, Day02);
+
 impl Solution for Day02 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         Some(
@@ -1,9 +1,11 @@
 use std::collections::HashMap;
 
-use crate::solutions::{Answer, Solution};
+use crate::solutions::{register_solver, Answer, Solution};
 
 pub struct Day03 {}
 
+register_solver!(2017, 3, Day03);
+
 impl Solution for Day03 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         let n: i64 = input.trim_end().parse().unwrap();
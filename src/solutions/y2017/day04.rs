@@ -2,10 +2,12 @@ use std::{collections::HashSet, iter::FromIterator};
 
 use itertools::Itertools;
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day04 {}
 
+register_solver!(2017, 4, Day04);
+
 impl Solution for Day04 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         fn is_valid_passphrase(passphrase: &str) -> bool {
@@ -1,7 +1,9 @@
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day05 {}
 
+register_solver!(2017, 5, Day05);
+
 fn parse(input: &str) -> Vec<i64> {
     input
         .split('\n')
@@ -3,10 +3,12 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day06 {}
 
+register_solver!(2017, 6, Day06);
+
 fn hash(blocks: &Vec<u32>) -> u64 {
     let mut hasher = DefaultHasher::new();
     blocks.hash(&mut hasher);
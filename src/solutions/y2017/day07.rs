@@ -6,10 +6,12 @@ use std::{
 
 use regex::Regex;
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day07 {}
 
+register_solver!(2017, 7, Day07);
+
 impl Solution for Day07 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         let mut map: HashMap<String, Vec<String>> = HashMap::new();
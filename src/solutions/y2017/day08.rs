@@ -1,9 +1,11 @@
 use std::collections::HashMap;
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day08 {}
 
+register_solver!(2017, 8, Day08);
+
 enum Command {
     Inc(i64),
     Dec(i64),
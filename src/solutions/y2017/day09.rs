@@ -1,7 +1,9 @@
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day09 {}
 
+register_solver!(2017, 9, Day09);
+
 impl Solution for Day09 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         let chars: Vec<char> = input.chars().collect();
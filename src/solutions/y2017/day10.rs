@@ -1,12 +1,14 @@
 use std::fmt::{Display, Formatter, Result};
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 const BLOCK_SIZE: usize = 16;
 const SIZE: usize = 256;
 
 pub struct Day10 {}
 
+register_solver!(2017, 10, Day10);
+
 impl Solution for Day10 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         let mut array: Vec<usize> = (0..SIZE).collect();
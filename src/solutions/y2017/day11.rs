@@ -1,9 +1,11 @@
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 // Cool website to explain calculations on hexagons https://www.redblobgames.com/grids/hexagons/
 
 pub struct Day11 {}
 
+register_solver!(2017, 11, Day11);
+
 /// Representing the different directions one can move in a vertically
 /// aligned hex grid.
 enum HexDirection {
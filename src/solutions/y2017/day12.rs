@@ -1,9 +1,11 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day12 {}
 
+register_solver!(2017, 12, Day12);
+
 type Relations = HashMap<usize, HashSet<usize>>;
 
 impl Day12 {
@@ -1,9 +1,11 @@
 use std::collections::HashMap;
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day13 {}
 
+register_solver!(2017, 13, Day13);
+
 impl Day13 {
     fn parse_firewall(input: &str) -> HashMap<usize, SecurityScanner> {
         input
@@ -4,10 +4,12 @@ use std::{
 };
 
 use super::day10::KnotHash;
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day14 {}
 
+register_solver!(2017, 14, Day14);
+
 impl Solution for Day14 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         let mut count: u32 = 0;
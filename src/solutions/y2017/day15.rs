@@ -2,10 +2,12 @@ use std::{convert::TryInto, iter::Scan, ops::Range};
 
 use regex::Regex;
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day15 {}
 
+register_solver!(2017, 15, Day15);
+
 impl Solution for Day15 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         let size = 40_000_000;
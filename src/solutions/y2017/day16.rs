@@ -1,9 +1,11 @@
 use std::iter::Map;
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day16;
 
+register_solver!(2017, 16, Day16);
+
 impl Solution for Day16 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         Some(Self::helper_a(input, 16).into())
@@ -1,7 +1,9 @@
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day17;
 
+register_solver!(2017, 17, Day17);
+
 impl Solution for Day17 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         let times = 2017;
@@ -36,9 +38,10 @@ impl Solution for Day17 {
 
 #[cfg(test)]
 mod test {
-    use advent_of_code_client::{AocClient, Year};
+    use advent_of_code_client::Year;
 
     use super::*;
+    use crate::utils::load_input;
 
     #[test]
     fn test_a() {
@@ -47,9 +50,7 @@ mod test {
 
     #[test]
     fn test_b() {
-        let input = AocClient::default()
-            .get_input((Year::Y2017, 17).into())
-            .unwrap();
+        let input = load_input((Year::Y2017, 17).into()).unwrap();
         assert_eq!(Day17 {}.solve_b(&input), Some(Answer::UInt(41797835)));
     }
 }
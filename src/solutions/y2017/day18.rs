@@ -1,72 +1,44 @@
-use std::{
-    collections::{HashMap, VecDeque},
-    iter::Map,
-    str::Lines,
-};
+use std::collections::VecDeque;
 
-use crate::solutions::{answer::Answer, Solution};
+use super::assembly::{parse_instruction, unsupported, RegOrVal, Registers};
+use crate::solutions::{
+    answer::Answer,
+    error::{parse_lines, LineError, SolutionError, SolutionResult},
+    register_solver, ParsedSolution,
+};
 
 pub struct Day18;
 
-impl Solution for Day18 {
-    fn solve_a(&self, input: &str) -> Option<Answer> {
-        Some(Inst::run_a(parse(input).collect()).unwrap().into())
-    }
-
-    fn solve_b(&self, input: &str) -> Option<Answer> {
-        Some(Inst::run_b(parse(input).collect()).into())
-    }
-}
-
-fn parse(input: &str) -> Map<Lines<'_>, fn(&str) -> Inst> {
-    input.lines().map(|l| l.into())
-}
+register_solver!(2017, 18, Day18);
 
-#[derive(PartialEq, Debug)]
-enum RegOrVal {
-    Register(char),
-    Value(i64),
-}
+impl ParsedSolution for Day18 {
+    type Input = Vec<Inst>;
 
-impl RegOrVal {
-    fn get_value(&self, registers: &Registers) -> i64 {
-        match self {
-            RegOrVal::Register(r) => *registers.get(r).unwrap_or(&0),
-            RegOrVal::Value(n) => *n,
-        }
+    fn parse(&self, input: &str) -> SolutionResult<Self::Input> {
+        parse_lines(input, Inst::parse)
     }
 
-    fn get_register(&self) -> char {
-        match self {
-            RegOrVal::Register(r) => *r,
-            RegOrVal::Value(_) => panic!("Cannot get register from value"),
-        }
+    fn solve_a(&self, input: &Self::Input) -> SolutionResult {
+        Inst::run_a(input)
+            .map(Answer::from)
+            .ok_or_else(|| SolutionError::no_solution("no sound was recovered"))
     }
-}
 
-impl From<&str> for RegOrVal {
-    fn from(value: &str) -> Self {
-        match value.parse::<i64>() {
-            Ok(n) => RegOrVal::Value(n),
-            Err(_) => match value.len() {
-                1 => RegOrVal::Register(value.chars().next().unwrap()),
-                _ => panic!("str is wrong length"),
-            },
-        }
+    fn solve_b(&self, input: &Self::Input) -> SolutionResult {
+        Ok(Inst::run_b(input).into())
     }
 }
 
-type Registers = HashMap<char, i64>;
 type Sounds = Vec<i64>;
 
 #[derive(PartialEq, Debug)]
-enum Inst {
+pub enum Inst {
     Snd(RegOrVal),
-    Set(RegOrVal, RegOrVal),
-    Add(RegOrVal, RegOrVal),
-    Mul(RegOrVal, RegOrVal),
-    Mod(RegOrVal, RegOrVal),
-    Rcv(RegOrVal),
+    Set(char, RegOrVal),
+    Add(char, RegOrVal),
+    Mul(char, RegOrVal),
+    Mod(char, RegOrVal),
+    Rcv(char),
     Jgz(RegOrVal, RegOrVal),
 }
 
@@ -74,25 +46,19 @@ impl Inst {
     fn execute(&self, registers: &mut Registers, index: &mut usize) -> ThreadState {
         match self {
             Inst::Set(x, y) => {
-                registers.insert(x.get_register(), y.get_value(registers));
+                registers.insert(*x, y.get_value(registers));
             }
             Inst::Add(x, y) => {
-                registers.insert(
-                    x.get_register(),
-                    x.get_value(registers) + y.get_value(registers),
-                );
+                let y = y.get_value(registers);
+                *registers.entry(*x).or_default() += y;
             }
             Inst::Mul(x, y) => {
-                registers.insert(
-                    x.get_register(),
-                    x.get_value(registers) * y.get_value(registers),
-                );
+                let y = y.get_value(registers);
+                *registers.entry(*x).or_default() *= y;
             }
             Inst::Mod(x, y) => {
-                registers.insert(
-                    x.get_register(),
-                    x.get_value(registers) % y.get_value(registers),
-                );
+                let y = y.get_value(registers);
+                *registers.entry(*x).or_default() %= y;
             }
             Inst::Jgz(x, y) => {
                 if x.get_value(registers) > 0 {
@@ -116,7 +82,7 @@ impl Inst {
         ThreadState::Ready
     }
 
-    fn run_a(instructions: Vec<Inst>) -> Option<i64> {
+    fn run_a(instructions: &[Inst]) -> Option<i64> {
         let mut registers = Registers::new();
         let mut sounds = Sounds::new();
         let mut index = 0;
@@ -128,7 +94,7 @@ impl Inst {
                     index += 1;
                 }
                 Inst::Rcv(x) => {
-                    if x.get_value(&registers) != 0 {
+                    if registers.get(x).is_some_and(|value| *value != 0) {
                         return sounds.pop();
                     }
                     index += 1;
@@ -140,17 +106,17 @@ impl Inst {
             };
         }
 
-        unreachable!();
+        None
     }
 
-    fn run_b(instructions: Vec<Inst>) -> u64 {
+    fn run_b(instructions: &[Inst]) -> u64 {
         let mut a_context = ThreadContext::new(0);
         let mut b_context = ThreadContext::new(1);
 
         fn run(
             context: &mut ThreadContext,
             other: &mut ThreadContext,
-            instructions: &Vec<Inst>,
+            instructions: &[Inst],
         ) -> ThreadState {
             while context.index < instructions.len() {
                 match &instructions[context.index] {
@@ -161,7 +127,7 @@ impl Inst {
                     }
                     Inst::Rcv(x) => {
                         if let Some(value) = context.queue.pop_front() {
-                            context.registers.insert(x.get_register(), value);
+                            context.registers.insert(*x, value);
                             context.index += 1;
                         } else {
                             return ThreadState::Waiting;
@@ -181,29 +147,32 @@ impl Inst {
 
         while a_context.can_run() || b_context.can_run() {
             // println!("Switching to A {:?}", a_context);
-            a_context.state = run(&mut a_context, &mut b_context, &instructions);
+            a_context.state = run(&mut a_context, &mut b_context, instructions);
             // println!("Switching to B {:?}", b_context);
-            b_context.state = run(&mut b_context, &mut a_context, &instructions);
+            b_context.state = run(&mut b_context, &mut a_context, instructions);
         }
 
         b_context.send_count
     }
 }
 
-impl From<&str> for Inst {
-    fn from(value: &str) -> Self {
-        let mut args = value.get(4..).unwrap().split(' ');
-        match value.get(0..=2) {
-            Some("snd") => Inst::Snd(args.next().unwrap().into()),
-            Some("set") => Inst::Set(args.next().unwrap().into(), args.next().unwrap().into()),
-            Some("add") => Inst::Add(args.next().unwrap().into(), args.next().unwrap().into()),
-            Some("mul") => Inst::Mul(args.next().unwrap().into(), args.next().unwrap().into()),
-            Some("mod") => Inst::Mod(args.next().unwrap().into(), args.next().unwrap().into()),
-            Some("rcv") => Inst::Rcv(args.next().unwrap().into()),
-            Some("jgz") => Inst::Jgz(args.next().unwrap().into(), args.next().unwrap().into()),
-            Some(x) => panic!("instrution '{}' not supported", x),
-            None => todo!(),
-        }
+impl Inst {
+    fn parse(line: &str) -> Result<Self, LineError> {
+        let (name, mut args) = parse_instruction(line);
+
+        let inst = match name {
+            "snd" => Inst::Snd(args.arg()?),
+            "set" => Inst::Set(args.register()?, args.arg()?),
+            "add" => Inst::Add(args.register()?, args.arg()?),
+            "mul" => Inst::Mul(args.register()?, args.arg()?),
+            "mod" => Inst::Mod(args.register()?, args.arg()?),
+            "rcv" => Inst::Rcv(args.register()?),
+            "jgz" => Inst::Jgz(args.arg()?, args.arg()?),
+            name => return Err(unsupported(name)),
+        };
+        args.end()?;
+
+        Ok(inst)
     }
 }
 
@@ -260,38 +229,21 @@ jgz a -2";
 
     #[test]
     fn parse_test() {
-        let mut it = parse(INPUT);
+        let instructions = Day18.parse(INPUT).unwrap();
+        let mut it = instructions.into_iter();
 
-        assert_eq!(
-            it.next().unwrap(),
-            Inst::Set(RegOrVal::Register('a'), RegOrVal::Value(1))
-        );
-        assert_eq!(
-            it.next().unwrap(),
-            Inst::Add(RegOrVal::Register('a'), RegOrVal::Value(2))
-        );
-        assert_eq!(
-            it.next().unwrap(),
-            Inst::Mul(RegOrVal::Register('a'), RegOrVal::Register('a'))
-        );
-        assert_eq!(
-            it.next().unwrap(),
-            Inst::Mod(RegOrVal::Register('a'), RegOrVal::Value(5))
-        );
+        assert_eq!(it.next().unwrap(), Inst::Set('a', RegOrVal::Value(1)));
+        assert_eq!(it.next().unwrap(), Inst::Add('a', RegOrVal::Value(2)));
+        assert_eq!(it.next().unwrap(), Inst::Mul('a', RegOrVal::Register('a')));
+        assert_eq!(it.next().unwrap(), Inst::Mod('a', RegOrVal::Value(5)));
         assert_eq!(it.next().unwrap(), Inst::Snd(RegOrVal::Register('a')));
-        assert_eq!(
-            it.next().unwrap(),
-            Inst::Set(RegOrVal::Register('a'), RegOrVal::Value(0))
-        );
-        assert_eq!(it.next().unwrap(), Inst::Rcv(RegOrVal::Register('a')));
+        assert_eq!(it.next().unwrap(), Inst::Set('a', RegOrVal::Value(0)));
+        assert_eq!(it.next().unwrap(), Inst::Rcv('a'));
         assert_eq!(
             it.next().unwrap(),
             Inst::Jgz(RegOrVal::Register('a'), RegOrVal::Value(-1))
         );
-        assert_eq!(
-            it.next().unwrap(),
-            Inst::Set(RegOrVal::Register('a'), RegOrVal::Value(1))
-        );
+        assert_eq!(it.next().unwrap(), Inst::Set('a', RegOrVal::Value(1)));
         assert_eq!(
             it.next().unwrap(),
             Inst::Jgz(RegOrVal::Register('a'), RegOrVal::Value(-2))
@@ -300,7 +252,10 @@ jgz a -2";
 
     #[test]
     fn test_a() {
-        assert_eq!(Day18 {}.solve_a(INPUT), Some(Answer::Int(4)))
+        assert_eq!(
+            Day18.solve_a(&Day18.parse(INPUT).unwrap()),
+            Ok(Answer::Int(4))
+        )
     }
 
     #[test]
@@ -312,6 +267,53 @@ rcv a
 rcv b
 rcv c
 rcv d";
-        assert_eq!(Day18 {}.solve_b(input), Some(Answer::UInt(3)))
+        assert_eq!(
+            Day18.solve_b(&Day18.parse(input).unwrap()),
+            Ok(Answer::UInt(3))
+        )
+    }
+
+    #[test]
+    fn parse_invalid_instruction() {
+        assert_eq!(
+            Day18.parse("set a 1\nadd a bc").err(),
+            Some(SolutionError::MalformedInput {
+                line: 2,
+                column: 7,
+                message: "'bc' is neither a register nor a number".to_string()
+            })
+        );
+        assert_eq!(
+            Day18.parse("set a 1\nnop").err(),
+            Some(SolutionError::MalformedInput {
+                line: 2,
+                column: 1,
+                message: "instruction 'nop' not supported".to_string()
+            })
+        );
+        assert_eq!(
+            Day18.parse("set 1 2").err(),
+            Some(SolutionError::MalformedInput {
+                line: 1,
+                column: 5,
+                message: "expected a register".to_string()
+            })
+        );
+        assert_eq!(
+            Day18.parse("rcv 5").err(),
+            Some(SolutionError::MalformedInput {
+                line: 1,
+                column: 5,
+                message: "expected a register".to_string()
+            })
+        );
+        assert_eq!(
+            Day18.parse("snd a b").err(),
+            Some(SolutionError::MalformedInput {
+                line: 1,
+                column: 7,
+                message: "unexpected argument".to_string()
+            })
+        );
     }
 }
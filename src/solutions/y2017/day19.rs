@@ -1,9 +1,11 @@
 use std::{fmt::Display, ops};
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day19;
 
+register_solver!(2017, 19, Day19);
+
 type Map = Vec<Vec<char>>;
 
 #[derive(Debug, Clone, Copy)]
@@ -6,10 +6,12 @@ use std::{
 
 use regex::Regex;
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day20;
 
+register_solver!(2017, 20, Day20);
+
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
 struct Vec3D {
     x: i64,
@@ -1,6 +1,6 @@
 use std::{collections::HashMap, str::FromStr};
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct Grid {
@@ -189,6 +189,8 @@ impl FromStr for Rules {
 
 pub struct Day21;
 
+register_solver!(2017, 21, Day21);
+
 impl Solution for Day21 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         let rules: &Rules = &input.parse().unwrap();
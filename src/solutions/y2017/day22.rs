@@ -1,6 +1,6 @@
 use std::{collections::HashMap, str::FromStr};
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 enum Direction {
     Up,
@@ -145,6 +145,8 @@ impl World {
 
 pub struct Day22;
 
+register_solver!(2017, 22, Day22);
+
 impl Solution for Day22 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         Some(Self::run(input, 10_000).into())
@@ -1,22 +1,31 @@
-use std::{collections::HashMap, str::FromStr};
-
-use crate::solutions::{answer::Answer, Solution};
+use super::assembly::{parse_instruction, unsupported, RegOrVal, Registers};
+use crate::solutions::{
+    error::{parse_lines, LineError, SolutionResult},
+    register_solver, ParsedSolution,
+};
 
 pub struct Day23;
 
-impl Solution for Day23 {
-    fn solve_a(&self, input: &str) -> Option<Answer> {
-        let instructions: Vec<Inst> = input.lines().map(|l| l.parse().unwrap()).collect();
+register_solver!(2017, 23, Day23);
+
+impl ParsedSolution for Day23 {
+    type Input = Vec<Inst>;
+
+    fn parse(&self, input: &str) -> SolutionResult<Self::Input> {
+        parse_lines(input, Inst::parse)
+    }
+
+    fn solve_a(&self, instructions: &Self::Input) -> SolutionResult {
         let mut context = ThreadContext::default();
 
-        while context.can_run(&instructions) {
+        while context.can_run(instructions) {
             instructions[context.index].execute(&mut context);
         }
 
-        Some(context.multi_count.into())
+        Ok(context.multi_count.into())
     }
 
-    fn solve_b(&self, _input: &str) -> Option<Answer> {
+    fn solve_b(&self, _input: &Self::Input) -> SolutionResult {
         // Optimized from the assembly code.
         // first manually calculate what b and c is set to at the beginning,
         // then the middle part of the algorithm is simply checking whether
@@ -30,7 +39,7 @@ impl Solution for Day23 {
             }
         }
 
-        Some(h.into())
+        Ok(h.into())
     }
 }
 
@@ -54,91 +63,47 @@ fn is_prime(n: i64) -> bool {
     true
 }
 
-type Registers = HashMap<char, i64>;
-
-#[derive(PartialEq, Debug)]
-enum RegOrVal {
-    Register(char),
-    Value(i64),
-}
-
-impl FromStr for RegOrVal {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.parse::<i64>() {
-            Ok(n) => Ok(RegOrVal::Value(n)),
-            Err(_) => match s.len() {
-                1 => Ok(RegOrVal::Register(s.chars().next().unwrap())),
-                _ => panic!("str is wrong length"),
-            },
-        }
-    }
-}
-
-impl RegOrVal {
-    fn get_value(&self, registers: &Registers) -> i64 {
-        match self {
-            RegOrVal::Register(r) => *registers.get(r).unwrap_or(&0),
-            RegOrVal::Value(n) => *n,
-        }
-    }
-
-    fn get_register(&self) -> char {
-        match self {
-            RegOrVal::Register(r) => *r,
-            RegOrVal::Value(_) => panic!("Cannot get register from value"),
-        }
-    }
-}
-
 #[derive(PartialEq, Debug)]
 enum ThreadState {
     Ready,
     Finished,
 }
 
-enum Inst {
-    Set(RegOrVal, RegOrVal),
-    Sub(RegOrVal, RegOrVal),
-    Mul(RegOrVal, RegOrVal),
+pub enum Inst {
+    Set(char, RegOrVal),
+    Sub(char, RegOrVal),
+    Mul(char, RegOrVal),
     Jnz(RegOrVal, RegOrVal),
 }
 
-impl FromStr for Inst {
-    type Err = ();
-
-    fn from_str(value: &str) -> Result<Self, Self::Err> {
-        let mut args = value.get(4..).unwrap().split(' ');
-        Ok(match value.get(0..=2) {
-            Some("set") => Inst::Set(args.next().unwrap().parse()?, args.next().unwrap().parse()?),
-            Some("sub") => Inst::Sub(args.next().unwrap().parse()?, args.next().unwrap().parse()?),
-            Some("mul") => Inst::Mul(args.next().unwrap().parse()?, args.next().unwrap().parse()?),
-            Some("jnz") => Inst::Jnz(args.next().unwrap().parse()?, args.next().unwrap().parse()?),
-            Some(x) => panic!("instrution '{}' not supported", x),
-            None => todo!(),
-        })
+impl Inst {
+    fn parse(line: &str) -> Result<Self, LineError> {
+        let (name, mut args) = parse_instruction(line);
+
+        let inst = match name {
+            "set" => Inst::Set(args.register()?, args.arg()?),
+            "sub" => Inst::Sub(args.register()?, args.arg()?),
+            "mul" => Inst::Mul(args.register()?, args.arg()?),
+            "jnz" => Inst::Jnz(args.arg()?, args.arg()?),
+            name => return Err(unsupported(name)),
+        };
+        args.end()?;
+
+        Ok(inst)
     }
-}
 
-impl Inst {
     fn execute(&self, ctx: &mut ThreadContext) {
         match self {
             Inst::Set(x, y) => {
-                ctx.registers
-                    .insert(x.get_register(), y.get_value(&ctx.registers));
+                ctx.registers.insert(*x, y.get_value(&ctx.registers));
             }
             Inst::Sub(x, y) => {
-                ctx.registers.insert(
-                    x.get_register(),
-                    x.get_value(&ctx.registers) - y.get_value(&ctx.registers),
-                );
+                let y = y.get_value(&ctx.registers);
+                *ctx.registers.entry(*x).or_default() -= y;
             }
             Inst::Mul(x, y) => {
-                ctx.registers.insert(
-                    x.get_register(),
-                    x.get_value(&ctx.registers) * y.get_value(&ctx.registers),
-                );
+                let y = y.get_value(&ctx.registers);
+                *ctx.registers.entry(*x).or_default() *= y;
                 ctx.multi_count += 1;
             }
             Inst::Jnz(x, y) => {
@@ -179,7 +144,33 @@ impl Default for ThreadContext {
 }
 
 impl ThreadContext {
-    fn can_run(&self, inst: &Vec<Inst>) -> bool {
+    fn can_run(&self, inst: &[Inst]) -> bool {
         self.state == ThreadState::Ready && self.index < inst.len()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::solutions::error::SolutionError;
+
+    #[test]
+    fn parse_invalid_instruction() {
+        assert_eq!(
+            Day23.parse("set a 1\nsub 2 a").err(),
+            Some(SolutionError::MalformedInput {
+                line: 2,
+                column: 5,
+                message: "expected a register".to_string()
+            })
+        );
+        assert_eq!(
+            Day23.parse("jnz a 2 3").err(),
+            Some(SolutionError::MalformedInput {
+                line: 1,
+                column: 9,
+                message: "unexpected argument".to_string()
+            })
+        );
+    }
+}
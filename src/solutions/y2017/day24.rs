@@ -2,10 +2,12 @@ use std::{fmt::Display, str::FromStr};
 
 use hashbag::HashBag;
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day24;
 
+register_solver!(2017, 24, Day24);
+
 impl Solution for Day24 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         let mut components = Port::to_bag(input);
@@ -1,12 +1,14 @@
 use std::collections::HashSet;
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 const RIGHT: i64 = 1;
 const LEFT: i64 = -1;
 
 pub struct Day25;
 
+register_solver!(2017, 25, Day25);
+
 impl Solution for Day25 {
     fn solve_a(&self, _input: &str) -> Option<Answer> {
         const ITERATIONS: usize = 12_172_063;
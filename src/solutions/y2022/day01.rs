@@ -1,9 +1,11 @@
 use itertools::Itertools;
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day01;
 
+register_solver!(2022, 1, Day01);
+
 impl Solution for Day01 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         Some(
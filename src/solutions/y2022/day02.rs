@@ -1,9 +1,11 @@
 use std::cmp::Ordering;
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day02;
 
+register_solver!(2022, 2, Day02);
+
 impl Solution for Day02 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         Some(
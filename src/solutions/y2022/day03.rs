@@ -1,9 +1,11 @@
 use std::collections::HashSet;
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day03;
 
+register_solver!(2022, 3, Day03);
+
 impl Solution for Day03 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         Some(
@@ -1,7 +1,9 @@
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day04;
 
+register_solver!(2022, 4, Day04);
+
 impl Solution for Day04 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         Some(
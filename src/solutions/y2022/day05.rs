@@ -3,10 +3,12 @@ use std::collections::HashMap;
 use itertools::Itertools;
 use regex::Regex;
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day05;
 
+register_solver!(2022, 5, Day05);
+
 impl Solution for Day05 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         let (mut stacks, commands) = parse(input);
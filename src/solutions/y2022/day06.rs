@@ -3,10 +3,12 @@ use std::{
     ops::{AddAssign, SubAssign},
 };
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day06;
 
+register_solver!(2022, 6, Day06);
+
 impl Solution for Day06 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         find_first_unique(input.trim_end(), 4).map(Answer::from)
@@ -2,10 +2,12 @@ use std::{cell::RefCell, collections::VecDeque, rc::Rc};
 
 use itertools::Either;
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day07;
 
+register_solver!(2022, 7, Day07);
+
 impl Solution for Day07 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         const LIMIT: usize = 100_000;
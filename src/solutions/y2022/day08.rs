@@ -1,12 +1,14 @@
 use array2d::Array2D;
 
 use crate::{
-    solutions::{answer::Answer, Solution},
+    solutions::{answer::Answer, register_solver, Solution},
     utils::take_until_inclusive::TakeUntilInclusiveExt,
 };
 
 pub struct Day08;
 
+register_solver!(2022, 8, Day08);
+
 impl Solution for Day08 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         let trees = parse(input);
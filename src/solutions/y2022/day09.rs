@@ -1,9 +1,11 @@
 use std::collections::HashSet;
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day09;
 
+register_solver!(2022, 9, Day09);
+
 impl Solution for Day09 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         Some(simulate(parse(input), 2).into())
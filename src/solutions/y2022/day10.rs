@@ -2,12 +2,14 @@ use advent_of_code_ocr::parse_string_to_letters;
 use array2d::Array2D;
 
 use crate::{
-    solutions::{answer::Answer, Solution},
+    solutions::{answer::Answer, register_solver, Solution},
     utils::ocr::screen_to_string,
 };
 
 pub struct Day10;
 
+register_solver!(2022, 10, Day10);
+
 impl Solution for Day10 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         let commands = parse(input);
@@ -1,18 +1,30 @@
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{
+    error::{parse_lines, LineError, SolutionError, SolutionResult},
+    register_solver, ParsedSolution,
+};
 
 pub struct Day11;
 
-impl Solution for Day11 {
-    fn solve_a(&self, input: &str) -> Option<Answer> {
-        Some(simulate(parse(input), 20, 3).into())
+register_solver!(2022, 11, Day11);
+
+impl ParsedSolution for Day11 {
+    type Input = MonkeysWithItems;
+
+    fn parse(&self, input: &str) -> SolutionResult<Self::Input> {
+        parse(input)
+    }
+
+    fn solve_a(&self, input: &Self::Input) -> SolutionResult {
+        Ok(simulate(input, 20, 3).into())
     }
 
-    fn solve_b(&self, input: &str) -> Option<Answer> {
-        Some(simulate(parse(input), 10_000, 1).into())
+    fn solve_b(&self, input: &Self::Input) -> SolutionResult {
+        Ok(simulate(input, 10_000, 1).into())
     }
 }
 
-fn simulate((monkeys, mut items): MonkeysWithItems, rounds: usize, divisor: u64) -> usize {
+fn simulate((monkeys, items): &MonkeysWithItems, rounds: usize, divisor: u64) -> usize {
+    let mut items = items.clone();
     let mut counts = vec![0; monkeys.len()];
     let common: u64 = monkeys.iter().map(|m| m.divisor).product();
 
@@ -41,27 +53,101 @@ fn simulate((monkeys, mut items): MonkeysWithItems, rounds: usize, divisor: u64)
 
 type MonkeysWithItems = (Vec<Monkey>, Vec<Vec<u64>>);
 
-fn parse(input: &str) -> MonkeysWithItems {
-    input
-        .trim_end()
-        .split("\n\n")
-        .map(|x| {
-            let (first, second) = x.split_once("Operation").unwrap();
-            let mut lines = first.lines().skip(1);
-            let items = parse_items(lines.next().unwrap());
-            let monkey = Monkey::try_from(second).unwrap();
-
-            (monkey, items)
+/// Number of lines for each monkey, including the empty line after it.
+const MONKEY_LINES: usize = 7;
+
+fn parse(input: &str) -> SolutionResult<MonkeysWithItems> {
+    let lines = parse_lines(input.trim_end(), parse_line)?;
+    let (monkeys, items): MonkeysWithItems = lines
+        .chunks(MONKEY_LINES)
+        .enumerate()
+        .map(|(i, chunk)| match chunk {
+            [Line::Monkey, Line::Items(items), Line::Operation(operation), Line::Divisor(divisor), Line::Throw(true, true_index), Line::Throw(false, false_index), rest @ ..]
+                if rest.is_empty() || rest == [Line::Empty] =>
+            {
+                let monkey = Monkey {
+                    operation: operation.clone(),
+                    divisor: *divisor,
+                    true_index: *true_index,
+                    false_index: *false_index,
+                };
+                Ok((monkey, items.clone()))
+            }
+            _ => Err(SolutionError::malformed(
+                i * MONKEY_LINES,
+                0,
+                "expected a monkey with its items, operation, test, and targets",
+            )),
         })
-        .unzip()
+        .collect::<SolutionResult<Vec<_>>>()?
+        .into_iter()
+        .unzip();
+
+    for (i, monkey) in monkeys.iter().enumerate() {
+        for (offset, target) in [(4, monkey.true_index), (5, monkey.false_index)] {
+            if target as usize >= monkeys.len() {
+                return Err(SolutionError::malformed(
+                    i * MONKEY_LINES + offset,
+                    0,
+                    format!("monkey {target} does not exist"),
+                ));
+            }
+        }
+    }
+
+    Ok((monkeys, items))
+}
+
+/// A single line in the description of a monkey.
+#[derive(Debug, Clone, PartialEq)]
+enum Line {
+    Monkey,
+    Items(Vec<u64>),
+    Operation(Operation),
+    Divisor(u64),
+    Throw(bool, u64),
+    Empty,
 }
 
-fn parse_items(input: &str) -> Vec<u64> {
-    input
-        .split([':', ','])
-        .skip(1)
-        .map(|x| x.trim().parse::<u64>().expect("to be number"))
-        .collect::<Vec<u64>>()
+fn parse_line(line: &str) -> Result<Line, LineError> {
+    let trimmed = line.trim();
+    if trimmed.is_empty() {
+        Ok(Line::Empty)
+    } else if trimmed.starts_with("Monkey ") {
+        Ok(Line::Monkey)
+    } else if let Some(items) = trimmed.strip_prefix("Starting items:") {
+        items
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(|item| parse_number(line, item))
+            .collect::<Result<_, _>>()
+            .map(Line::Items)
+    } else if let Some(operation) = trimmed.strip_prefix("Operation: new = old ") {
+        match operation.split_whitespace().collect::<Vec<_>>()[..] {
+            ["*", "old"] => Ok(Line::Operation(Operation::Square)),
+            ["+", value] => Ok(Line::Operation(Operation::Add(parse_number(line, value)?))),
+            ["*", value] => Ok(Line::Operation(Operation::Mul(parse_number(line, value)?))),
+            _ => Err(LineError::at(line, operation, "unknown operation")),
+        }
+    } else if let Some(divisor) = trimmed.strip_prefix("Test: divisible by ") {
+        match parse_number(line, divisor)? {
+            0 => Err(LineError::at(line, divisor, "divisor must not be zero")),
+            divisor => Ok(Line::Divisor(divisor)),
+        }
+    } else if let Some(target) = trimmed.strip_prefix("If true: throw to monkey ") {
+        Ok(Line::Throw(true, parse_number(line, target)?))
+    } else if let Some(target) = trimmed.strip_prefix("If false: throw to monkey ") {
+        Ok(Line::Throw(false, parse_number(line, target)?))
+    } else {
+        Err(LineError::at(line, trimmed, "unknown line"))
+    }
+}
+
+fn parse_number(line: &str, token: &str) -> Result<u64, LineError> {
+    token
+        .parse()
+        .map_err(|_| LineError::at(line, token, format!("'{token}' is not a number")))
 }
 
 #[allow(dead_code)]
@@ -72,54 +158,15 @@ fn print_items(monkeys: &[Vec<u64>]) {
 }
 
 #[derive(Debug, Clone, PartialEq)]
-struct Monkey {
+pub struct Monkey {
     operation: Operation,
     divisor: u64,
     true_index: u64,
     false_index: u64,
 }
 
-impl TryFrom<&str> for Monkey {
-    type Error = ();
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let mut lines = value.lines();
-        let operation = lines
-            .next()
-            .map(|x| {
-                let parts: Vec<&str> = x.split_whitespace().collect();
-
-                match parts[..] {
-                    [.., "*", "old"] => Operation::Square,
-                    [.., "+", v] => Operation::Add(v.parse().unwrap()),
-                    [.., "*", v] => Operation::Mul(v.parse().unwrap()),
-                    _ => panic!("Unknown operation"),
-                }
-            })
-            .unwrap();
-
-        let divisor = lines
-            .next()
-            .unwrap()
-            .split(" by ")
-            .nth(1)
-            .unwrap()
-            .parse()
-            .unwrap();
-
-        let mut indexes = lines.map(|l| l.chars().last().unwrap().to_digit(10).unwrap() as u64);
-
-        Ok(Self {
-            operation,
-            divisor,
-            true_index: indexes.next().unwrap(),
-            false_index: indexes.next().unwrap(),
-        })
-    }
-}
-
 #[derive(Debug, Clone, PartialEq)]
-enum Operation {
+pub enum Operation {
     Add(u64),
     Mul(u64),
     Square,
@@ -140,39 +187,64 @@ mod test {
     use advent_of_code_client::Year;
 
     use super::*;
-    use crate::utils::load_sample;
+    use crate::{solutions::answer::Answer, utils::load_sample};
+
+    const MONKEY: &str = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 0
+    If false: throw to monkey 0";
 
     #[test]
     fn parse_monkey() {
-        let sample = "Operation: new = old * 19
-Test: divisible by 23
-    If true: throw to monkey 2
-    If false: throw to monkey 3";
+        assert_eq!(
+            parse(MONKEY),
+            Ok((
+                vec![Monkey {
+                    operation: Operation::Mul(19),
+                    divisor: 23,
+                    true_index: 0,
+                    false_index: 0
+                }],
+                vec![vec![79, 98]]
+            ))
+        )
+    }
 
-        let monkey = Monkey::try_from(sample);
+    #[test]
+    fn parse_invalid_monkey() {
         assert_eq!(
-            monkey,
-            Ok(Monkey {
-                operation: Operation::Mul(19),
-                divisor: 23,
-                true_index: 2,
-                false_index: 3
+            parse(&MONKEY.replace("98", "9x")).err(),
+            Some(SolutionError::MalformedInput {
+                line: 2,
+                column: 23,
+                message: "'9x' is not a number".to_string()
             })
-        )
+        );
+        assert_eq!(
+            parse(&MONKEY.replace("If false: throw to monkey 0", "If false: throw to monkey 1"))
+                .err(),
+            Some(SolutionError::MalformedInput {
+                line: 6,
+                column: 1,
+                message: "monkey 1 does not exist".to_string()
+            })
+        );
     }
 
     #[test]
     fn test_a() {
         assert_eq!(
-            Day11.solve_a(load_sample(Year::Y2022, "11.txt").unwrap().as_str()),
-            Some(Answer::UInt(10605))
+            Day11.solve_a(&parse(&load_sample(Year::Y2022, "11.txt").unwrap()).unwrap()),
+            Ok(Answer::UInt(10605))
         )
     }
     #[test]
     fn test_b() {
         assert_eq!(
-            Day11.solve_b(load_sample(Year::Y2022, "11.txt").unwrap().as_str()),
-            Some(Answer::UInt(2713310158))
+            Day11.solve_b(&parse(&load_sample(Year::Y2022, "11.txt").unwrap()).unwrap()),
+            Ok(Answer::UInt(2713310158))
         )
     }
 }
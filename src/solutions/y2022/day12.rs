@@ -3,10 +3,12 @@ use std::{cmp::Reverse, collections::HashSet, fmt::Display};
 use array2d::Array2D;
 use priority_queue::PriorityQueue;
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day12;
 
+register_solver!(2022, 12, Day12);
+
 impl Solution for Day12 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         let (map, start, end, _) = parse(input);
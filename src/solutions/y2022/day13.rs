@@ -1,9 +1,11 @@
 use std::{cmp::Ordering, collections::VecDeque};
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day13;
 
+register_solver!(2022, 13, Day13);
+
 impl Solution for Day13 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         Some(
@@ -127,9 +129,10 @@ fn parse_packet(line: &str) -> Packet {
 
 #[cfg(test)]
 mod test {
+    use advent_of_code_client::Year;
+
     use super::*;
     use crate::utils::load_sample;
-    use advent_of_code_client::Year;
 
     #[test]
     fn test_a() {
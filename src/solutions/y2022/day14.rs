@@ -1,10 +1,13 @@
-use crate::solutions::{answer::Answer, Solution};
 use advent_of_code_client::Level;
 use array2d::Array2D;
 use itertools::{Itertools, MinMaxResult};
 
+use crate::solutions::{answer::Answer, register_solver, Solution};
+
 pub struct Day14;
 
+register_solver!(2022, 14, Day14);
+
 impl Solution for Day14 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         let (mut map, x_bounds, y_bounds) = create_map(parse(input));
@@ -1,11 +1,13 @@
 use lazy_static::lazy_static;
 use regex::Regex;
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 const PART_B_MAX: isize = 4_000_000;
 
 pub struct Day15;
 
+register_solver!(2022, 15, Day15);
+
 impl Solution for Day15 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         const ROW: isize = 2_000_000;
@@ -109,9 +111,10 @@ impl From<(isize, isize)> for Point {
 
 #[cfg(test)]
 mod test {
+    use advent_of_code_client::Year;
+
     use super::*;
     use crate::utils::load_sample;
-    use advent_of_code_client::Year;
 
     #[test]
     fn test_a() {
@@ -8,12 +8,14 @@ use pathfinding::prelude::{astar, dijkstra_all};
 use regex::Regex;
 
 use crate::{
-    solutions::{answer::Answer, Solution},
+    solutions::{answer::Answer, register_solver, Solution},
     utils::bit_set::BitSet,
 };
 
 pub struct Day16;
 
+register_solver!(2022, 16, Day16);
+
 impl Solution for Day16 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         let (valves, start) = helper(input);
@@ -378,9 +380,10 @@ fn one_actor_cost(
 
 #[cfg(test)]
 mod tests {
+    use advent_of_code_client::Year;
+
     use super::*;
     use crate::utils::load_sample;
-    use advent_of_code_client::Year;
 
     lazy_static! {
         static ref SAMPLE_INPUT: String = load_sample(Year::Y2022, "16.txt").unwrap();
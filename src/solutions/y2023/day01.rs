@@ -1,9 +1,11 @@
 use regex::Regex;
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day01;
 
+register_solver!(2023, 1, Day01);
+
 impl Solution for Day01 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         let re = Regex::new(r"\d").unwrap();
@@ -76,9 +78,10 @@ impl Solution for Day01 {
 
 #[cfg(test)]
 mod test {
-    use advent_of_code_client::{AocClient, Year};
+    use advent_of_code_client::Year;
 
     use super::*;
+    use crate::utils::load_input;
 
     #[test]
     fn test_a() {
@@ -91,9 +94,7 @@ treb7uchet"#;
 
     #[test]
     fn solve_a() {
-        let input = AocClient::default()
-            .get_input((Year::Y2023, 1).into())
-            .unwrap();
+        let input = load_input((Year::Y2023, 1).into()).unwrap();
         assert_eq!(Day01 {}.solve_a(&input), Some(Answer::UInt(54916)));
     }
 
@@ -111,9 +112,7 @@ zoneight234
 
     #[test]
     fn solve_b() {
-        let input = AocClient::default()
-            .get_input((Year::Y2023, 1).into())
-            .unwrap();
+        let input = load_input((Year::Y2023, 1).into()).unwrap();
         assert_eq!(Day01 {}.solve_b(&input), Some(Answer::UInt(54728)));
     }
 }
@@ -1,10 +1,12 @@
 use lazy_static::lazy_static;
 use regex::Regex;
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day02;
 
+register_solver!(2023, 2, Day02);
+
 impl Solution for Day02 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         let limits = Set {
@@ -103,9 +105,10 @@ impl Set {
 
 #[cfg(test)]
 mod test {
-    use advent_of_code_client::{AocClient, Problem, Year};
+    use advent_of_code_client::{Problem, Year};
 
     use super::*;
+    use crate::utils::load_input;
 
     const PROBLEM: Problem = Problem::new(Year::Y2023, 2);
     const INPUT: &str = r#"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
@@ -121,7 +124,7 @@ Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"#;
 
     #[test]
     fn solve_a() {
-        let input = AocClient::default().get_input(PROBLEM).unwrap();
+        let input = load_input(PROBLEM).unwrap();
         assert_eq!(Day02 {}.solve_a(&input), Some(Answer::UInt(2795)));
     }
 
@@ -132,7 +135,7 @@ Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"#;
 
     #[test]
     fn solve_b() {
-        let input = AocClient::default().get_input(PROBLEM).unwrap();
+        let input = load_input(PROBLEM).unwrap();
         assert_eq!(Day02 {}.solve_b(&input), Some(Answer::UInt(75561)));
     }
 }
@@ -3,10 +3,12 @@ use std::collections::HashSet;
 use itertools::Itertools;
 use regex::Regex;
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day03;
 
+register_solver!(2023, 3, Day03);
+
 impl Solution for Day03 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         let numbers = find_numbers(input);
@@ -94,9 +96,10 @@ fn adjencent(number: &Number) -> Vec<(usize, usize)> {
 
 #[cfg(test)]
 mod test {
-    use advent_of_code_client::{AocClient, Problem, Year};
+    use advent_of_code_client::{Problem, Year};
 
     use super::*;
+    use crate::utils::load_input;
 
     const PROBLEM: Problem = Problem::new(Year::Y2023, 3);
     const INPUT: &str = r#"467..114..
@@ -122,13 +125,13 @@ mod test {
 
     #[test]
     fn answer_a() {
-        let input = AocClient::default().get_input(PROBLEM).unwrap();
+        let input = load_input(PROBLEM).unwrap();
         assert_eq!(Day03 {}.solve_a(input.as_str()), Some(Answer::UInt(509115)));
     }
 
     #[test]
     fn answer_b() {
-        let input = AocClient::default().get_input(PROBLEM).unwrap();
+        let input = load_input(PROBLEM).unwrap();
         assert_eq!(
             Day03 {}.solve_b(input.as_str()),
             Some(Answer::UInt(75220503))
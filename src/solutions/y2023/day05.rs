@@ -1,84 +1,122 @@
-use std::{collections::HashMap, ops::Range};
+use std::ops::Range;
 
 use rangemap::RangeMap;
-use regex::Regex;
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{
+    error::{LineError, SolutionError, SolutionResult},
+    register_solver, ParsedSolution,
+};
 
 pub struct Day05;
 
-impl Solution for Day05 {
-    fn solve_a(&self, input: &str) -> Option<Answer> {
-        let mut parts = input.split("\n\n");
-        let seeds = parts
-            .next()
-            .and_then(|l| l.strip_prefix("seeds: "))
-            .map(|numbers| {
-                numbers
-                    .split_whitespace()
-                    .filter_map(|x| x.parse::<usize>().ok())
-                    .collect::<Vec<_>>()
-            })
-            .unwrap();
-        // println!("Parsed seeds => {seeds:?}");
-        let mapping = create_mapping(parts);
+register_solver!(2023, 5, Day05);
+
+impl ParsedSolution for Day05 {
+    type Input = Almanac;
+
+    fn parse(&self, input: &str) -> SolutionResult<Self::Input> {
+        let mut lines = input.lines().enumerate();
+        let first = lines.next().map_or("", |(_, line)| line);
+        let seeds = first
+            .strip_prefix("seeds: ")
+            .ok_or_else(|| {
+                SolutionError::malformed(0, 0, "expected input to start with 'seeds: '")
+            })?
+            .split_whitespace()
+            .map(|token| parse_number(first, token))
+            .collect::<Result<_, _>>()
+            .map_err(|e| e.on_line(0))?;
+
+        let mut maps: Vec<RangeMap<isize, isize>> = Vec::new();
+        for (index, line) in lines {
+            if line.is_empty() {
+                continue;
+            }
+            if line.ends_with("map:") {
+                maps.push(RangeMap::new());
+                continue;
+            }
+
+            let map = maps.last_mut().ok_or_else(|| {
+                SolutionError::malformed(index, 0, "expected a map header before its ranges")
+            })?;
+            let (range, offset) = parse_range(line).map_err(|e| e.on_line(index))?;
+            map.insert(range, offset);
+        }
+
+        Ok(Almanac { seeds, maps })
+    }
 
-        let answer = seeds
+    fn solve_a(&self, input: &Self::Input) -> SolutionResult {
+        let answer = input
+            .seeds
             .iter()
-            .map(|s| to_location(&mapping, *s))
+            .map(|seed| {
+                input
+                    .maps
+                    .iter()
+                    .fold(*seed, |value, map| value + map.get(&value).unwrap_or(&0))
+            })
             .min()
-            .unwrap();
+            .ok_or_else(|| SolutionError::no_solution("almanac has no seeds"))?;
 
-        Some(answer.into())
+        Ok((answer as usize).into())
     }
 
-    fn solve_b(&self, input: &str) -> Option<Answer> {
-        let mut parts = input.split("\n\n");
-        let seeds = parts
-            .next()
-            .and_then(|l| l.strip_prefix("seeds: "))
-            .map(|numbers| {
-                numbers
-                    .split_whitespace()
-                    .filter_map(|x| x.parse::<isize>().ok())
-                    .collect::<Vec<_>>()
-                    .chunks(2)
-                    .map(|v| (v[0]..v[0] + v[1]))
-                    .collect::<Vec<_>>()
-            })
-            .unwrap();
+    fn solve_b(&self, input: &Self::Input) -> SolutionResult {
+        let pairs = input.seeds.chunks_exact(2);
+        if !pairs.remainder().is_empty() {
+            return Err(SolutionError::malformed(
+                0,
+                "seeds: ".len(),
+                "seeds must come in start/length pairs",
+            ));
+        }
+        let seeds = pairs.map(|v| v[0]..v[0] + v[1]).collect::<Vec<_>>();
 
-        let answer = parse(parts)
+        let answer = input
+            .maps
             .iter()
             .fold(seeds, map_range)
             .iter()
             .map(|range| range.start)
             .min()
-            .unwrap();
+            .ok_or_else(|| SolutionError::no_solution("almanac has no seeds"))?;
 
-        Some(answer.into())
+        Ok(answer.into())
     }
 }
 
-fn parse<'a>(lines: impl Iterator<Item = &'a str>) -> Vec<RangeMap<isize, isize>> {
-    let re = Regex::new(r"(?<distination>\d+) (?<source>\d+) (?<length>\d+)").unwrap();
-
-    lines
-        .map(|block| {
-            block
-                .lines()
-                .skip(1)
-                .map(|line| {
-                    let m = re.captures(line).unwrap();
-                    let source = m["source"].parse::<isize>().unwrap();
-                    let destination = m["distination"].parse::<isize>().unwrap();
-                    let length = m["length"].parse::<isize>().unwrap();
-
-                    (source..source + length, destination - source)
-                })
-                .collect::<RangeMap<_, _>>()
-        })
-        .collect()
+pub struct Almanac {
+    seeds: Vec<isize>,
+    maps: Vec<RangeMap<isize, isize>>,
+}
+
+/// Parse a line with the destination, source, and length of a range into
+/// the source range and the offset to its destination.
+fn parse_range(line: &str) -> Result<(Range<isize>, isize), LineError> {
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+    let [destination, source, length] = tokens[..] else {
+        return Err(LineError::new(
+            0,
+            "expected destination, source, and length",
+        ));
+    };
+    let destination = parse_number(line, destination)?;
+    let source = parse_number(line, source)?;
+    let length_token = length;
+    let length = parse_number(line, length)?;
+    if length <= 0 {
+        return Err(LineError::at(line, length_token, "length must be positive"));
+    }
+
+    Ok((source..source + length, destination - source))
+}
+
+fn parse_number(line: &str, token: &str) -> Result<isize, LineError> {
+    token
+        .parse()
+        .map_err(|_| LineError::at(line, token, format!("'{token}' is not a number")))
 }
 
 fn map_range(mut inputs: Vec<Range<isize>>, map: &RangeMap<isize, isize>) -> Vec<Range<isize>> {
@@ -104,88 +142,12 @@ fn map_range(mut inputs: Vec<Range<isize>>, map: &RangeMap<isize, isize>) -> Vec
     output
 }
 
-// Naive implementation for part A.
-type Map<'a> = HashMap<&'a str, Converter>;
-
-fn to_location(mapping: &Map, seed: usize) -> usize {
-    let mut current = "seed";
-    let mut value = seed;
-    while current != "location" {
-        let converter = mapping.get(current).unwrap();
-        value = converter.convert(value);
-        current = converter.to.as_str();
-    }
-
-    value
-}
-
-fn create_mapping<'a>(parts: impl Iterator<Item = &'a str>) -> Map<'a> {
-    let re = Regex::new(r"(?<distination>\d+) (?<source>\d+) (?<length>\d+)").unwrap();
-
-    parts
-        .map(|l| {
-            let mut lines = l.lines();
-            let (from, to) = lines
-                .next()
-                .and_then(|x| x.strip_suffix(" map:"))
-                .and_then(|l| l.split_once("-to-"))
-                .unwrap();
-
-            let mapping: Vec<_> = lines
-                .map(|line| {
-                    let m = re.captures(line).unwrap();
-                    Mapping {
-                        source: m["source"].parse().unwrap(),
-                        distination: m["distination"].parse().unwrap(),
-                        range: m["length"].parse().unwrap(),
-                    }
-                })
-                .collect();
-
-            (
-                from,
-                Converter {
-                    to: to.to_string(),
-                    mapping,
-                },
-            )
-        })
-        .collect()
-}
-
-struct Converter {
-    to: String,
-    mapping: Vec<Mapping>,
-}
-
-impl Converter {
-    fn convert(&self, value: usize) -> usize {
-        self.mapping
-            .iter()
-            .find(|m| m.source <= value && value < m.source + m.range)
-            .map(|m| (value as isize + m.diff()) as usize)
-            .unwrap_or(value)
-    }
-}
-
-#[derive(Debug)]
-struct Mapping {
-    source: usize,
-    distination: usize,
-    range: usize,
-}
-
-impl Mapping {
-    fn diff(&self) -> isize {
-        self.distination as isize - self.source as isize
-    }
-}
-
 #[cfg(test)]
 mod test {
-    use advent_of_code_client::{AocClient, Problem, Year};
+    use advent_of_code_client::{Problem, Year};
 
     use super::*;
+    use crate::{solutions::answer::Answer, utils::load_input};
 
     const PROBLEM: Problem = Problem::new(Year::Y2023, 5);
     const INPUT: &str = r#"seeds: 79 14 55 13
@@ -225,20 +187,56 @@ humidity-to-location map:
 
     #[test]
     fn test_a() {
-        assert_eq!(Day05 {}.solve_a(INPUT), Some(Answer::UInt(35)));
+        assert_eq!(
+            Day05.solve_a(&Day05.parse(INPUT).unwrap()),
+            Ok(Answer::UInt(35))
+        );
     }
 
     #[test]
     fn test_b() {
-        assert_eq!(Day05 {}.solve_b(INPUT), Some(Answer::Int(46)));
+        assert_eq!(
+            Day05.solve_b(&Day05.parse(INPUT).unwrap()),
+            Ok(Answer::Int(46))
+        );
+    }
+
+    #[test]
+    fn parse_invalid_almanac() {
+        assert_eq!(
+            Day05.parse("seeds: 79 1x4").err(),
+            Some(SolutionError::MalformedInput {
+                line: 1,
+                column: 11,
+                message: "'1x4' is not a number".to_string()
+            })
+        );
+        assert_eq!(
+            Day05
+                .parse("seeds: 79 14\n\nseed-to-soil map:\n50 98")
+                .err(),
+            Some(SolutionError::MalformedInput {
+                line: 4,
+                column: 1,
+                message: "expected destination, source, and length".to_string()
+            })
+        );
+        assert_eq!(
+            Day05.solve_b(&Day05.parse("seeds: 79 14 55").unwrap()),
+            Err(SolutionError::MalformedInput {
+                line: 1,
+                column: 8,
+                message: "seeds must come in start/length pairs".to_string()
+            })
+        );
     }
 
     #[test]
     fn answer_b() {
-        let input = AocClient::default().get_input(PROBLEM).unwrap();
+        let input = load_input(PROBLEM).unwrap();
         assert_eq!(
-            Day05 {}.solve_b(input.as_str()),
-            Some(Answer::Int(41222968))
+            Day05.solve_b(&Day05.parse(&input).unwrap()),
+            Ok(Answer::Int(41222968))
         );
     }
 }
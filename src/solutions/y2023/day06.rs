@@ -1,9 +1,11 @@
 use std::time::Instant;
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day06;
 
+register_solver!(2023, 6, Day06);
+
 impl Solution for Day06 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         let races = parse(input);
@@ -107,9 +109,10 @@ fn parse(input: &str) -> Vec<Race> {
 
 #[cfg(test)]
 mod test {
-    use advent_of_code_client::{AocClient, Problem, Year};
+    use advent_of_code_client::{Problem, Year};
 
     use super::*;
+    use crate::utils::load_input;
 
     const PROBLEM: Problem = Problem::new(Year::Y2023, 6);
     const INPUT: &str = r#"Time:      7  15   30
@@ -122,7 +125,7 @@ Distance:  9  40  200"#;
 
     #[test]
     fn solve_a() {
-        let input = AocClient::default().get_input(PROBLEM).unwrap();
+        let input = load_input(PROBLEM).unwrap();
         assert_eq!(Day06 {}.solve_a(&input), Some(Answer::UInt(220320)));
     }
 
@@ -133,7 +136,7 @@ Distance:  9  40  200"#;
 
     #[test]
     fn solve_b() {
-        let input = AocClient::default().get_input(PROBLEM).unwrap();
+        let input = load_input(PROBLEM).unwrap();
         assert_eq!(Day06 {}.solve_b(&input), Some(Answer::UInt(34454850)));
     }
 }
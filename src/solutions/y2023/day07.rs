@@ -2,10 +2,12 @@ use std::{cmp::Ordering, collections::HashMap};
 
 use itertools::Itertools;
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day07;
 
+register_solver!(2023, 7, Day07);
+
 impl Solution for Day07 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         let answer = input
@@ -168,9 +170,10 @@ impl From<&str> for CardType {
 
 #[cfg(test)]
 mod test {
-    use advent_of_code_client::{AocClient, Problem, Year};
+    use advent_of_code_client::{Problem, Year};
 
     use super::*;
+    use crate::utils::load_input;
 
     const PROBLEM: Problem = Problem::new(Year::Y2023, 7);
     const INPUT: &str = r#"32T3K 765
@@ -187,7 +190,7 @@ QQQJA 483
 
     #[test]
     fn solve_a() {
-        let input = AocClient::default().get_input(PROBLEM).unwrap();
+        let input = load_input(PROBLEM).unwrap();
         assert_eq!(Day07 {}.solve_a(&input), Some(Answer::UInt(249748283)));
     }
 
@@ -198,7 +201,7 @@ QQQJA 483
 
     #[test]
     fn solve_b() {
-        let input = AocClient::default().get_input(PROBLEM).unwrap();
+        let input = load_input(PROBLEM).unwrap();
         assert_eq!(Day07 {}.solve_b(&input), Some(Answer::UInt(248029057)));
     }
 }
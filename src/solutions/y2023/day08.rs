@@ -2,12 +2,14 @@ use regex::Regex;
 use rustc_hash::FxHashMap;
 
 use crate::{
-    solutions::{answer::Answer, Solution},
+    solutions::{answer::Answer, register_solver, Solution},
     utils::math,
 };
 
 pub struct Day08;
 
+register_solver!(2023, 8, Day08);
+
 impl Solution for Day08 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         let (path, network) = parse(input);
@@ -76,9 +78,10 @@ fn parse(input: &str) -> (&str, FxHashMap<String, (String, String)>) {
 
 #[cfg(test)]
 mod test {
-    use advent_of_code_client::{AocClient, Problem, Year};
+    use advent_of_code_client::{Problem, Year};
 
     use super::*;
+    use crate::utils::load_input;
 
     const PROBLEM: Problem = Problem::new(Year::Y2023, 8);
     const INPUT: &str = r#"LLR
@@ -95,7 +98,7 @@ ZZZ = (ZZZ, ZZZ)
 
     #[test]
     fn solve_a() {
-        let input = AocClient::default().get_input(PROBLEM).unwrap();
+        let input = load_input(PROBLEM).unwrap();
         assert_eq!(Day08 {}.solve_a(&input), Some(Answer::UInt(19637)));
     }
 
@@ -117,7 +120,7 @@ XXX = (XXX, XXX)
 
     #[test]
     fn solve_b() {
-        let input = AocClient::default().get_input(PROBLEM).unwrap();
+        let input = load_input(PROBLEM).unwrap();
         assert_eq!(Day08 {}.solve_b(&input), Some(Answer::UInt(8811050362409)));
     }
 }
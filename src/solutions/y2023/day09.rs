@@ -1,7 +1,9 @@
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day09;
 
+register_solver!(2023, 9, Day09);
+
 impl Solution for Day09 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         let answer: isize = input
@@ -51,9 +53,10 @@ fn solve<F: FnMut(&Vec<isize>)>(mut aggregate: F, mut numbers: Vec<isize>) {
 
 #[cfg(test)]
 mod test {
-    use advent_of_code_client::{AocClient, Problem, Year};
+    use advent_of_code_client::{Problem, Year};
 
     use super::*;
+    use crate::utils::load_input;
 
     const PROBLEM: Problem = Problem::new(Year::Y2023, 9);
     const INPUT: &str = r#"0 3 6 9 12 15
@@ -68,7 +71,7 @@ mod test {
 
     #[test]
     fn solve_a() {
-        let input = AocClient::default().get_input(PROBLEM).unwrap();
+        let input = load_input(PROBLEM).unwrap();
         assert_eq!(Day09 {}.solve_a(&input), Some(Answer::Int(1681758908)));
     }
 
@@ -79,7 +82,7 @@ mod test {
 
     #[test]
     fn solve_b() {
-        let input = AocClient::default().get_input(PROBLEM).unwrap();
+        let input = load_input(PROBLEM).unwrap();
         assert_eq!(Day09 {}.solve_b(&input), Some(Answer::Int(803)));
     }
 }
@@ -19,12 +19,14 @@ use array2d::Array2D;
 use rustc_hash::FxHashSet;
 
 use crate::{
-    solutions::{answer::Answer, Solution},
+    solutions::{answer::Answer, register_solver, Solution},
     utils::map2d::{Direction, Position},
 };
 
 pub struct Day10;
 
+register_solver!(2023, 10, Day10);
+
 // The pipes are arranged in a two-dimensional grid of tiles:
 
 //     | is a vertical pipe connecting north and south.
@@ -228,9 +230,10 @@ fn find_starting_neighbours(map: &Map, pos: Position) -> Vec<(Position, Directio
 
 #[cfg(test)]
 mod test {
-    use advent_of_code_client::{AocClient, Problem, Year};
+    use advent_of_code_client::{Problem, Year};
 
     use super::*;
+    use crate::utils::load_input;
 
     const PROBLEM: Problem = Problem::new(Year::Y2023, 10);
     const INPUT: &str = r#".....
@@ -255,7 +258,7 @@ LJ...
 
     #[test]
     fn solve_a() {
-        let input = AocClient::default().get_input(PROBLEM).unwrap();
+        let input = load_input(PROBLEM).unwrap();
         assert_eq!(Day10 {}.solve_a(&input), Some(Answer::UInt(6882)));
     }
 
@@ -309,7 +312,7 @@ L7JLJL-JLJLJL--JLJ.L"#;
 
     #[test]
     fn solve_b() {
-        let input = AocClient::default().get_input(PROBLEM).unwrap();
+        let input = load_input(PROBLEM).unwrap();
         assert_eq!(Day10 {}.solve_b(&input), Some(Answer::UInt(491)));
     }
 }
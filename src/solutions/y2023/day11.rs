@@ -1,12 +1,14 @@
 use itertools::Itertools;
 use rustc_hash::FxHashSet;
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 const GALAXY: char = '#';
 
 pub struct Day11;
 
+register_solver!(2023, 11, Day11);
+
 impl Solution for Day11 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         let answer = solve(input, 2);
@@ -83,9 +85,10 @@ fn manhattan_distance(a: &Position, b: &Position) -> usize {
 
 #[cfg(test)]
 mod test {
-    use advent_of_code_client::{AocClient, Problem, Year};
+    use advent_of_code_client::{Problem, Year};
 
     use super::*;
+    use crate::utils::load_input;
 
     const PROBLEM: Problem = Problem::new(Year::Y2023, 11);
     const INPUT: &str = r#"...#......
@@ -107,7 +110,7 @@ mod test {
 
     #[test]
     fn solve_a() {
-        let input = AocClient::default().get_input(PROBLEM).unwrap();
+        let input = load_input(PROBLEM).unwrap();
         assert_eq!(Day11 {}.solve_a(&input), Some(Answer::UInt(9639160)));
     }
 
@@ -119,7 +122,7 @@ mod test {
 
     #[test]
     fn solve_b() {
-        let input = AocClient::default().get_input(PROBLEM).unwrap();
+        let input = load_input(PROBLEM).unwrap();
         assert_eq!(Day11 {}.solve_b(&input), Some(Answer::UInt(752936133304)));
     }
 }
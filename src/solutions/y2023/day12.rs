@@ -2,10 +2,12 @@ use itertools::Itertools;
 use rayon::prelude::*;
 use rustc_hash::FxHashMap;
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day12;
 
+register_solver!(2023, 12, Day12);
+
 impl Solution for Day12 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         let answer: usize = input
@@ -92,9 +94,10 @@ fn possible_ways(cache: &mut Cache, s: &[u8], within: Option<usize>, rest: &[usi
 
 #[cfg(test)]
 mod test {
-    use advent_of_code_client::{AocClient, Problem, Year};
+    use advent_of_code_client::{Problem, Year};
 
     use super::*;
+    use crate::utils::load_input;
 
     const PROBLEM: Problem = Problem::new(Year::Y2023, 12);
     const INPUT: &str = r#"???.### 1,1,3
@@ -111,7 +114,7 @@ mod test {
 
     #[test]
     fn solve_a() {
-        let input = AocClient::default().get_input(PROBLEM).unwrap();
+        let input = load_input(PROBLEM).unwrap();
         assert_eq!(Day12 {}.solve_a(&input), Some(Answer::UInt(7599)));
     }
 
@@ -122,7 +125,7 @@ mod test {
 
     #[test]
     fn solve_b() {
-        let input = AocClient::default().get_input(PROBLEM).unwrap();
+        let input = load_input(PROBLEM).unwrap();
         assert_eq!(Day12 {}.solve_b(&input), Some(Answer::UInt(15454556629917)));
     }
 }
@@ -1,10 +1,12 @@
 use array2d::Array2D;
 use duplicate::duplicate_item;
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day13;
 
+register_solver!(2023, 13, Day13);
+
 impl Solution for Day13 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         Some(solve(input, 0).into())
@@ -63,9 +65,10 @@ fn direction(image: &Image, expected: usize) -> Option<usize> {
 
 #[cfg(test)]
 mod test {
-    use advent_of_code_client::{AocClient, Problem, Year};
+    use advent_of_code_client::{Problem, Year};
 
     use super::*;
+    use crate::utils::load_input;
 
     const PROBLEM: Problem = Problem::new(Year::Y2023, 13);
     const INPUT: &str = r#"#.##..##.
@@ -92,7 +95,7 @@ mod test {
 
     #[test]
     fn solve_a() {
-        let input = AocClient::default().get_input(PROBLEM).unwrap();
+        let input = load_input(PROBLEM).unwrap();
         assert_eq!(Day13 {}.solve_a(&input), Some(Answer::UInt(37113)));
     }
 
@@ -103,7 +106,7 @@ mod test {
 
     #[test]
     fn solve_b() {
-        let input = AocClient::default().get_input(PROBLEM).unwrap();
+        let input = load_input(PROBLEM).unwrap();
         assert_eq!(Day13 {}.solve_b(&input), Some(Answer::UInt(30449)));
     }
 }
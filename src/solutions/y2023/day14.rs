@@ -2,7 +2,7 @@ use std::collections::VecDeque;
 
 use array2d::Array2D;
 
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 const ROCK_ROUND: char = 'O';
 const ROCK_CUBE: char = '#';
@@ -11,6 +11,8 @@ const CYCLES: usize = 1_000_000_000;
 
 pub struct Day14;
 
+register_solver!(2023, 14, Day14);
+
 impl Solution for Day14 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         let world = parse_world(input);
@@ -154,9 +156,10 @@ fn rotate_clockwise(world: &World) -> World {
 
 #[cfg(test)]
 mod test {
-    use advent_of_code_client::{AocClient, Problem, Year};
+    use advent_of_code_client::{Problem, Year};
 
     use super::*;
+    use crate::utils::load_input;
 
     const PROBLEM: Problem = Problem::new(Year::Y2023, 14);
     const INPUT: &str = r#"O....#....
@@ -178,7 +181,7 @@ O.#..O.#.#
 
     #[test]
     fn solve_a() {
-        let input = AocClient::default().get_input(PROBLEM).unwrap();
+        let input = load_input(PROBLEM).unwrap();
         assert_eq!(Day14 {}.solve_a(&input), Some(Answer::UInt(113078)));
     }
 
@@ -189,7 +192,7 @@ O.#..O.#.#
 
     #[test]
     fn solve_b() {
-        let input = AocClient::default().get_input(PROBLEM).unwrap();
+        let input = load_input(PROBLEM).unwrap();
         assert_eq!(Day14 {}.solve_b(&input), Some(Answer::UInt(94255)));
     }
 }
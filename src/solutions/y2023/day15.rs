@@ -1,7 +1,9 @@
-use crate::solutions::{answer::Answer, Solution};
+use crate::solutions::{answer::Answer, register_solver, Solution};
 
 pub struct Day15;
 
+register_solver!(2023, 15, Day15);
+
 impl Solution for Day15 {
     fn solve_a(&self, input: &str) -> Option<Answer> {
         Some(
@@ -122,10 +124,11 @@ struct Entry<'a> {
 
 #[cfg(test)]
 mod test {
-    use advent_of_code_client::{AocClient, Problem, Year};
+    use advent_of_code_client::{Problem, Year};
     use rstest::rstest;
 
     use super::*;
+    use crate::utils::load_input;
 
     const PROBLEM: Problem = Problem::new(Year::Y2023, 15);
     const INPUT: &str = r#"rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7"#;
@@ -143,7 +146,7 @@ mod test {
 
     #[test]
     fn solve_a() {
-        let input = AocClient::default().get_input(PROBLEM).unwrap();
+        let input = load_input(PROBLEM).unwrap();
         assert_eq!(Day15 {}.solve_a(&input), Some(Answer::UInt(513643)));
     }
 
@@ -154,7 +157,7 @@ mod test {
 
     #[test]
     fn solve_b() {
-        let input = AocClient::default().get_input(PROBLEM).unwrap();
+        let input = load_input(PROBLEM).unwrap();
         assert_eq!(Day15 {}.solve_b(&input), Some(Answer::UInt(265345)));
     }
 }
@@ -1,6 +1,6 @@
 use std::{env, fs, io::Error, string::String};
 
-use advent_of_code_client::Year;
+use advent_of_code_client::{default_input_provider, Problem, Year};
 
 pub mod bit_set;
 pub mod map2d;
@@ -14,3 +14,9 @@ pub fn load_sample(year: Year, name: &str) -> Result<String, Error> {
         env::var("CARGO_MANIFEST_DIR").unwrap() + &format!("/samples/{}/{name}", year.as_int());
     fs::read_to_string(path)
 }
+
+/// Load the personal input for a problem. Without a token, or in offline
+/// mode, only cached inputs are available.
+pub fn load_input(problem: Problem) -> advent_of_code_client::Result<String> {
+    default_input_provider()?.get_input(problem)
+}